@@ -0,0 +1,411 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::crc16_x25;
+use crate::jobs::Job;
+use crate::mqtt::telemetry::TelemetryMessage;
+
+/// HDLC frame delimiter.
+const FLAG: u8 = 0x7E;
+/// Control field of an unnumbered information (UI) frame.
+pub const UI_CONTROL: u8 = 0x03;
+/// Protocol identifier for "no layer 3 protocol".
+pub const NO_LAYER_3: u8 = 0xF0;
+/// Maximum number of digipeaters in the address field.
+const MAX_REPEATERS: usize = 8;
+/// Length of an encoded address, in bytes.
+const ADDRESS_LENGTH: usize = 7;
+
+/// Error type for AX.25 encoding and decoding failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ax25Error {
+    /// The callsign is empty, longer than 6 characters or not uppercase
+    /// alphanumeric
+    InvalidCallsign,
+    /// The SSID is greater than 15
+    InvalidSsid,
+    /// The frame is too short to hold addresses, control and FCS
+    TooShort,
+    /// The address field does not end before the frame does
+    UnterminatedAddress,
+    /// The frame lists more than 8 digipeaters
+    TooManyRepeaters,
+    /// The frame check sequence does not match the frame contents
+    FcsMismatch { expected: u16, found: u16 },
+}
+
+impl fmt::Display for Ax25Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Ax25Error::*;
+        match self {
+            InvalidCallsign => {
+                f.write_str("callsign is not 1 to 6 uppercase alphanumeric characters")
+            }
+            InvalidSsid => f.write_str("SSID is greater than 15"),
+            TooShort => f.write_str("frame is too short"),
+            UnterminatedAddress => f.write_str("address field is not terminated"),
+            TooManyRepeaters => f.write_str("more than 8 digipeaters"),
+            FcsMismatch { expected, found } => write!(
+                f,
+                "FCS mismatch: expected {expected:#06x}, found {found:#06x}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Ax25Error {}
+
+/// # AX.25 Address
+///
+/// A station callsign with its secondary station identifier.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "callsign": "LU1ABC",
+///   "ssid": 7
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    /// Callsign, up to 6 uppercase letters and digits.
+    #[schema(example = "LU1ABC")]
+    pub callsign: String,
+    /// Secondary station identifier, `0..=15`.
+    #[schema(example = 7)]
+    pub ssid: u8,
+}
+
+/// A digipeater in the address field of a frame.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct Repeater {
+    pub address: Address,
+    /// Whether the frame has already been relayed by this digipeater.
+    pub repeated: bool,
+}
+
+/// # AX.25 Frame
+///
+/// A link layer frame, without HDLC flags. Information and UI frames carry
+/// a protocol identifier; other frame types have `pid` set to `None`.
+///
+/// ## Example
+/// ```
+/// use rustar_types::codec::{Address, Ax25Frame};
+///
+/// let frame = Ax25Frame::ui(
+///     Address::new("CQ", 0).unwrap(),
+///     Address::new("LU1ABC", 7).unwrap(),
+///     b"Hello".to_vec(),
+/// );
+///
+/// let bytes = frame.encode();
+/// assert_eq!(Ax25Frame::decode(&bytes).unwrap(), frame);
+///
+/// // A corrupted frame fails its FCS check
+/// let mut corrupted = bytes.clone();
+/// corrupted[15] ^= 0x01;
+/// assert!(Ax25Frame::decode(&corrupted).is_err());
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct Ax25Frame {
+    pub destination: Address,
+    pub source: Address,
+    /// Digipeater path, in relay order.
+    pub repeaters: Vec<Repeater>,
+    /// Whether this is a command frame (as opposed to a response).
+    pub command: bool,
+    /// Control field.
+    pub control: u8,
+    /// Protocol identifier, present in I and UI frames.
+    pub pid: Option<u8>,
+    /// Information field.
+    pub info: Vec<u8>,
+}
+
+impl Address {
+    pub fn new(callsign: impl Into<String>, ssid: u8) -> Result<Self, Ax25Error> {
+        let callsign = callsign.into();
+        if callsign.is_empty()
+            || callsign.len() > 6
+            || !callsign
+                .bytes()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            return Err(Ax25Error::InvalidCallsign);
+        }
+        if ssid > 15 {
+            return Err(Ax25Error::InvalidSsid);
+        }
+        Ok(Address { callsign, ssid })
+    }
+
+    /// Encodes the address, setting the C/H bit to `flag` and the extension
+    /// bit if it is the `last` one.
+    fn encode(&self, flag: bool, last: bool) -> [u8; ADDRESS_LENGTH] {
+        let mut bytes = [b' ' << 1; ADDRESS_LENGTH];
+        for (byte, c) in bytes.iter_mut().zip(self.callsign.bytes()) {
+            *byte = c << 1;
+        }
+        bytes[6] = ((flag as u8) << 7) | 0x60 | (self.ssid << 1) | last as u8;
+        bytes
+    }
+
+    /// Decodes an address, returning it with its C/H and extension bits.
+    fn decode(bytes: &[u8]) -> Result<(Self, bool, bool), Ax25Error> {
+        let callsign: String = bytes[..6].iter().map(|byte| (byte >> 1) as char).collect();
+        let ssid = bytes[6];
+        let address = Address::new(callsign.trim_end(), (ssid >> 1) & 0x0F)?;
+        Ok((address, ssid & 0x80 != 0, ssid & 0x01 != 0))
+    }
+}
+
+impl Ax25Frame {
+    /// Builds a UI command frame with no layer 3 protocol, the usual
+    /// framing of amateur satellite telemetry and telecommands.
+    pub fn ui(destination: Address, source: Address, info: Vec<u8>) -> Self {
+        Ax25Frame {
+            destination,
+            source,
+            repeaters: Vec::new(),
+            command: true,
+            control: UI_CONTROL,
+            pid: Some(NO_LAYER_3),
+            info,
+        }
+    }
+
+    /// Encodes the frame, followed by its frame check sequence.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            ADDRESS_LENGTH * (2 + self.repeaters.len()) + 2 + self.info.len() + 2,
+        );
+        bytes.extend(self.destination.encode(self.command, false));
+        bytes.extend(self.source.encode(!self.command, self.repeaters.is_empty()));
+        for (i, repeater) in self.repeaters.iter().enumerate() {
+            let last = i + 1 == self.repeaters.len();
+            bytes.extend(repeater.address.encode(repeater.repeated, last));
+        }
+        bytes.push(self.control);
+        bytes.extend(self.pid);
+        bytes.extend(&self.info);
+
+        let fcs = crc16_x25(&bytes);
+        bytes.extend(fcs.to_le_bytes());
+        bytes
+    }
+
+    /// Decodes a frame and verifies its frame check sequence.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Ax25Error> {
+        if bytes.len() < 2 * ADDRESS_LENGTH + 1 + 2 {
+            return Err(Ax25Error::TooShort);
+        }
+
+        let (body, fcs) = bytes.split_at(bytes.len() - 2);
+        let found = u16::from_le_bytes([fcs[0], fcs[1]]);
+        let expected = crc16_x25(body);
+        if found != expected {
+            return Err(Ax25Error::FcsMismatch { expected, found });
+        }
+
+        let mut addresses = Vec::new();
+        let mut position = 0;
+        loop {
+            let Some(field) = body.get(position..position + ADDRESS_LENGTH) else {
+                return Err(Ax25Error::UnterminatedAddress);
+            };
+            let (address, flag, last) = Address::decode(field)?;
+            addresses.push((address, flag));
+            position += ADDRESS_LENGTH;
+            if last {
+                break;
+            }
+        }
+        if addresses.len() < 2 {
+            return Err(Ax25Error::TooShort);
+        }
+        if addresses.len() > 2 + MAX_REPEATERS {
+            return Err(Ax25Error::TooManyRepeaters);
+        }
+
+        let Some(&control) = body.get(position) else {
+            return Err(Ax25Error::TooShort);
+        };
+        position += 1;
+
+        // Only I frames (bit 0 clear) and UI frames carry a PID
+        let pid = if control & 0x01 == 0 || control & !0x10 == UI_CONTROL {
+            let Some(&pid) = body.get(position) else {
+                return Err(Ax25Error::TooShort);
+            };
+            position += 1;
+            Some(pid)
+        } else {
+            None
+        };
+
+        let mut addresses = addresses.into_iter();
+        let (destination, command) = addresses.next().unwrap();
+        let (source, _) = addresses.next().unwrap();
+        let repeaters = addresses
+            .map(|(address, repeated)| Repeater { address, repeated })
+            .collect();
+
+        Ok(Ax25Frame {
+            destination,
+            source,
+            repeaters,
+            command,
+            control,
+            pid,
+            info: body[position..].to_vec(),
+        })
+    }
+}
+
+impl TelemetryMessage {
+    /// Decodes the payload as an AX.25 frame.
+    pub fn ax25_frame(&self) -> Result<Ax25Frame, Ax25Error> {
+        Ax25Frame::decode(&self.payload)
+    }
+}
+
+impl Job {
+    /// Wraps the uplink data in a UI frame from `source` to `destination`,
+    /// if the job has any.
+    pub fn uplink_frame(&self, destination: Address, source: Address) -> Option<Ax25Frame> {
+        self.uplink
+            .as_ref()
+            .map(|uplink| Ax25Frame::ui(destination, source, uplink.clone()))
+    }
+}
+
+/// Wraps an encoded frame in HDLC flags and applies bit stuffing, returning
+/// the bits in transmission order (least significant bit first).
+///
+/// `flags` is the number of opening flags, at least one; more give the
+/// receiver time to synchronize.
+///
+/// ## Example
+/// ```
+/// use rustar_types::codec::{Address, Ax25Frame, hdlc_decode, hdlc_encode, nrzi_decode, nrzi_encode};
+///
+/// let frame = Ax25Frame::ui(
+///     Address::new("CQ", 0).unwrap(),
+///     Address::new("LU1ABC", 7).unwrap(),
+///     vec![0xFF; 4],
+/// );
+///
+/// let line = nrzi_encode(&hdlc_encode(&frame.encode(), 4), false);
+/// let frames = hdlc_decode(&nrzi_decode(&line, false));
+/// assert_eq!(Ax25Frame::decode(&frames[0]).unwrap(), frame);
+/// ```
+pub fn hdlc_encode(frame: &[u8], flags: usize) -> Vec<bool> {
+    let byte_bits = |byte: u8| (0..8).map(move |i| (byte >> i) & 1 != 0);
+
+    let mut bits = Vec::with_capacity((flags + 1 + frame.len()) * 8 + frame.len() * 2);
+    for _ in 0..flags.max(1) {
+        bits.extend(byte_bits(FLAG));
+    }
+
+    let mut ones = 0;
+    for bit in frame.iter().flat_map(|&byte| byte_bits(byte)) {
+        bits.push(bit);
+        if bit {
+            ones += 1;
+            if ones == 5 {
+                bits.push(false);
+                ones = 0;
+            }
+        } else {
+            ones = 0;
+        }
+    }
+
+    bits.extend(byte_bits(FLAG));
+    bits
+}
+
+/// Extracts the frames delimited by HDLC flags from a bitstream, removing
+/// bit stuffing.
+///
+/// Aborted frames (seven or more consecutive ones) and frames that are not
+/// a whole number of bytes are dropped. The frames still include their
+/// FCS, to be checked by [`Ax25Frame::decode`].
+pub fn hdlc_decode(bits: &[bool]) -> Vec<Vec<u8>> {
+    let mut frames = Vec::new();
+    let mut current: Option<Vec<bool>> = None;
+    let mut ones = 0;
+
+    for &bit in bits {
+        if bit {
+            ones += 1;
+            if ones > 6 {
+                current = None;
+            } else if let Some(current) = current.as_mut() {
+                current.push(true);
+            }
+            continue;
+        }
+
+        match ones {
+            // Stuffed bit
+            5 => {}
+            // Flag: the buffer ends with its leading zero and six ones
+            6 => {
+                if let Some(mut frame) = current.take() {
+                    frame.truncate(frame.len().saturating_sub(7));
+                    if !frame.is_empty() && frame.len() % 8 == 0 {
+                        frames.push(
+                            frame
+                                .chunks(8)
+                                .map(|byte| {
+                                    byte.iter()
+                                        .rev()
+                                        .fold(0, |acc, &bit| (acc << 1) | bit as u8)
+                                })
+                                .collect(),
+                        );
+                    }
+                }
+                current = Some(Vec::new());
+            }
+            _ => {
+                if let Some(current) = current.as_mut() {
+                    current.push(false);
+                }
+            }
+        }
+        ones = 0;
+    }
+
+    frames
+}
+
+/// NRZI-encodes a bitstream: a zero toggles the line level and a one keeps
+/// it. `level` is the line level before the first bit.
+pub fn nrzi_encode(bits: &[bool], mut level: bool) -> Vec<bool> {
+    bits.iter()
+        .map(|&bit| {
+            if !bit {
+                level = !level;
+            }
+            level
+        })
+        .collect()
+}
+
+/// Decodes an NRZI line into bits. `level` is the line level before the
+/// first sample.
+pub fn nrzi_decode(levels: &[bool], mut level: bool) -> Vec<bool> {
+    levels
+        .iter()
+        .map(|&current| {
+            let bit = current == level;
+            level = current;
+            bit
+        })
+        .collect()
+}
@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{CcsdsError, SpacePacket, require};
+use crate::codec::crc16_ccitt;
+use crate::mqtt::telemetry::TelemetryMessage;
+
+/// Length of the TM frame primary header, in bytes.
+const TM_HEADER_LENGTH: usize = 6;
+/// Length of the TC frame primary header, in bytes.
+const TC_HEADER_LENGTH: usize = 5;
+/// Length of the operational control field, in bytes.
+const OCF_LENGTH: usize = 4;
+/// Length of the frame error control field, in bytes.
+const FECF_LENGTH: usize = 2;
+/// First header pointer value meaning no packet starts in the frame.
+const NO_PACKET_START: u16 = 0x7FE;
+/// First header pointer value of frames that only carry idle data.
+const IDLE_DATA: u16 = 0x7FF;
+/// Maximum TC frame length, in bytes.
+const TC_MAX_LENGTH: usize = 1024;
+
+/// # TM Transfer Frame
+///
+/// A CCSDS telemetry transfer frame. Frames have a fixed, mission-defined
+/// length, and may end with a frame error control field (FECF), a
+/// CRC-16/CCITT-FALSE over the rest of the frame.
+///
+/// ## Example
+/// ```
+/// use rustar_types::codec::{PacketType, SequenceTracker, SpacePacket, TmFrame};
+///
+/// let packet = SpacePacket::new(PacketType::Telemetry, 100, 0, vec![0xAB; 8]);
+/// let frame = TmFrame::new(42, 1, 7, 3, packet.encode().unwrap());
+///
+/// let bytes = frame.encode(true).unwrap();
+/// let decoded = TmFrame::decode(&bytes, true).unwrap();
+/// assert_eq!(decoded, frame);
+/// assert_eq!(decoded.packets().unwrap(), vec![packet]);
+///
+/// // Virtual channel frame counts reveal lost frames
+/// let mut tracker = SequenceTracker::new(TmFrame::FRAME_COUNT_MODULUS);
+/// assert!(tracker.observe(1, 3).is_none());
+/// assert_eq!(tracker.observe(1, 6).unwrap().missing, 2);
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct TmFrame {
+    /// Spacecraft identifier, `0..=1023`.
+    #[schema(example = 42)]
+    pub spacecraft_id: u16,
+    /// Virtual channel identifier, `0..=7`.
+    #[schema(example = 1)]
+    pub virtual_channel: u8,
+    /// Frame count of the master channel.
+    pub master_frame_count: u8,
+    /// Frame count of the virtual channel.
+    pub virtual_frame_count: u8,
+    /// Offset of the first packet header in `data`, or `None` when the
+    /// frame only continues a packet from previous frames.
+    pub first_header_pointer: Option<u16>,
+    /// Data field.
+    pub data: Vec<u8>,
+    /// Operational control field (e.g. a CLCW), if present.
+    pub ocf: Option<u32>,
+}
+
+/// # TC Transfer Frame
+///
+/// A CCSDS telecommand transfer frame, whose encoded bytes are suitable as
+/// [`Job::uplink`](crate::jobs::Job) data.
+///
+/// ## Example
+/// ```
+/// use rustar_types::codec::{PacketType, SpacePacket, TcFrame};
+///
+/// let command = SpacePacket::new(PacketType::Telecommand, 100, 0, vec![0x01]);
+/// let frame = TcFrame::new(42, 0, 5, command.encode().unwrap());
+///
+/// let uplink = frame.encode(true).unwrap();
+/// assert_eq!(TcFrame::decode(&uplink, true).unwrap(), frame);
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct TcFrame {
+    /// Whether the frame bypasses the spacecraft's acceptance checks
+    /// (type B) instead of being sequence-controlled (type A).
+    pub bypass: bool,
+    /// Whether the data field holds control commands rather than user data.
+    pub control_command: bool,
+    /// Spacecraft identifier, `0..=1023`.
+    #[schema(example = 42)]
+    pub spacecraft_id: u16,
+    /// Virtual channel identifier, `0..=63`.
+    #[schema(example = 0)]
+    pub virtual_channel: u8,
+    /// Frame sequence number.
+    pub sequence_number: u8,
+    /// Data field.
+    pub data: Vec<u8>,
+}
+
+impl TmFrame {
+    /// Frame counts wrap to zero at this value.
+    pub const FRAME_COUNT_MODULUS: u32 = 256;
+
+    /// Builds a frame without OCF whose data starts with a packet header.
+    pub fn new(
+        spacecraft_id: u16,
+        virtual_channel: u8,
+        master_frame_count: u8,
+        virtual_frame_count: u8,
+        data: Vec<u8>,
+    ) -> Self {
+        TmFrame {
+            spacecraft_id,
+            virtual_channel,
+            master_frame_count,
+            virtual_frame_count,
+            first_header_pointer: Some(0),
+            data,
+            ocf: None,
+        }
+    }
+
+    /// Encodes the frame, appending a FECF if `fecf` is set.
+    pub fn encode(&self, fecf: bool) -> Result<Vec<u8>, CcsdsError> {
+        if self.spacecraft_id > 0x3FF {
+            return Err(CcsdsError::InvalidSpacecraftId(self.spacecraft_id));
+        }
+        if self.virtual_channel > 7 {
+            return Err(CcsdsError::InvalidVirtualChannel(self.virtual_channel));
+        }
+        let pointer = self.first_header_pointer.unwrap_or(NO_PACKET_START);
+        if pointer > IDLE_DATA {
+            return Err(CcsdsError::InvalidFirstHeaderPointer(pointer));
+        }
+
+        let identification = self.spacecraft_id << 4
+            | (self.virtual_channel as u16) << 1
+            | self.ocf.is_some() as u16;
+        // No secondary header, synchronous packets, segment length id 0b11
+        let status = 0x1800 | pointer;
+
+        let mut bytes =
+            Vec::with_capacity(TM_HEADER_LENGTH + self.data.len() + OCF_LENGTH + FECF_LENGTH);
+        bytes.extend(identification.to_be_bytes());
+        bytes.push(self.master_frame_count);
+        bytes.push(self.virtual_frame_count);
+        bytes.extend(status.to_be_bytes());
+        bytes.extend(&self.data);
+        if let Some(ocf) = self.ocf {
+            bytes.extend(ocf.to_be_bytes());
+        }
+        if fecf {
+            bytes.extend(crc16_ccitt(&bytes).to_be_bytes());
+        }
+        Ok(bytes)
+    }
+
+    /// Decodes a whole frame, verifying its FECF if `fecf` is set.
+    pub fn decode(bytes: &[u8], fecf: bool) -> Result<Self, CcsdsError> {
+        let bytes = if fecf { check_fecf(bytes)? } else { bytes };
+        require(bytes, TM_HEADER_LENGTH)?;
+
+        let identification = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let status = u16::from_be_bytes([bytes[4], bytes[5]]);
+        let version = (identification >> 14) as u8;
+        if version != 0 {
+            return Err(CcsdsError::UnsupportedVersion(version));
+        }
+
+        let has_ocf = identification & 0x0001 != 0;
+        let data_end = bytes.len() - if has_ocf { OCF_LENGTH } else { 0 };
+        require(
+            bytes,
+            TM_HEADER_LENGTH + if has_ocf { OCF_LENGTH } else { 0 },
+        )?;
+        let pointer = status & 0x07FF;
+
+        Ok(TmFrame {
+            spacecraft_id: (identification >> 4) & 0x3FF,
+            virtual_channel: ((identification >> 1) & 0x7) as u8,
+            master_frame_count: bytes[2],
+            virtual_frame_count: bytes[3],
+            first_header_pointer: (pointer != NO_PACKET_START).then_some(pointer),
+            data: bytes[TM_HEADER_LENGTH..data_end].to_vec(),
+            ocf: has_ocf.then(|| u32::from_be_bytes(bytes[data_end..].try_into().unwrap())),
+        })
+    }
+
+    /// Whether the frame only carries idle data.
+    pub fn is_idle(&self) -> bool {
+        self.first_header_pointer == Some(IDLE_DATA)
+    }
+
+    /// Decodes the Space Packets that start in this frame and are wholly
+    /// contained in it, skipping idle packets.
+    ///
+    /// Packets spanning several frames must be reassembled by the caller
+    /// from the data of consecutive frames of the same virtual channel.
+    pub fn packets(&self) -> Result<Vec<SpacePacket>, CcsdsError> {
+        let mut packets = Vec::new();
+        let Some(pointer) = self.first_header_pointer.filter(|_| !self.is_idle()) else {
+            return Ok(packets);
+        };
+
+        let mut data = self.data.get(pointer as usize..).unwrap_or_default();
+        while !data.is_empty() {
+            let (packet, length) = match SpacePacket::decode(data) {
+                Ok(decoded) => decoded,
+                // The last packet continues in the next frame
+                Err(CcsdsError::TooShort { .. }) => break,
+                Err(error) => return Err(error),
+            };
+            if !packet.is_idle() {
+                packets.push(packet);
+            }
+            data = &data[length..];
+        }
+        Ok(packets)
+    }
+}
+
+impl TcFrame {
+    /// Frame sequence numbers wrap to zero at this value.
+    pub const SEQUENCE_MODULUS: u32 = 256;
+
+    /// Builds a sequence-controlled data frame.
+    pub fn new(
+        spacecraft_id: u16,
+        virtual_channel: u8,
+        sequence_number: u8,
+        data: Vec<u8>,
+    ) -> Self {
+        TcFrame {
+            bypass: false,
+            control_command: false,
+            spacecraft_id,
+            virtual_channel,
+            sequence_number,
+            data,
+        }
+    }
+
+    /// Encodes the frame, appending a FECF if `fecf` is set.
+    pub fn encode(&self, fecf: bool) -> Result<Vec<u8>, CcsdsError> {
+        if self.spacecraft_id > 0x3FF {
+            return Err(CcsdsError::InvalidSpacecraftId(self.spacecraft_id));
+        }
+        if self.virtual_channel > 0x3F {
+            return Err(CcsdsError::InvalidVirtualChannel(self.virtual_channel));
+        }
+        let length = TC_HEADER_LENGTH + self.data.len() + if fecf { FECF_LENGTH } else { 0 };
+        if self.data.is_empty() || length > TC_MAX_LENGTH {
+            return Err(CcsdsError::InvalidDataLength(self.data.len()));
+        }
+
+        let identification =
+            (self.bypass as u16) << 13 | (self.control_command as u16) << 12 | self.spacecraft_id;
+        let channel = (self.virtual_channel as u16) << 10 | (length - 1) as u16;
+
+        let mut bytes = Vec::with_capacity(length);
+        bytes.extend(identification.to_be_bytes());
+        bytes.extend(channel.to_be_bytes());
+        bytes.push(self.sequence_number);
+        bytes.extend(&self.data);
+        if fecf {
+            bytes.extend(crc16_ccitt(&bytes).to_be_bytes());
+        }
+        Ok(bytes)
+    }
+
+    /// Decodes a whole frame, verifying its FECF if `fecf` is set.
+    pub fn decode(bytes: &[u8], fecf: bool) -> Result<Self, CcsdsError> {
+        require(bytes, TC_HEADER_LENGTH)?;
+
+        let identification = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let channel = u16::from_be_bytes([bytes[2], bytes[3]]);
+        let version = (identification >> 14) as u8;
+        if version != 0 {
+            return Err(CcsdsError::UnsupportedVersion(version));
+        }
+        let declared = (channel & 0x03FF) as usize + 1;
+        if declared != bytes.len() {
+            return Err(CcsdsError::LengthMismatch {
+                declared,
+                found: bytes.len(),
+            });
+        }
+
+        let bytes = if fecf { check_fecf(bytes)? } else { bytes };
+        require(bytes, TC_HEADER_LENGTH)?;
+
+        Ok(TcFrame {
+            bypass: identification & 0x2000 != 0,
+            control_command: identification & 0x1000 != 0,
+            spacecraft_id: identification & 0x03FF,
+            virtual_channel: (channel >> 10) as u8,
+            sequence_number: bytes[4],
+            data: bytes[TC_HEADER_LENGTH..].to_vec(),
+        })
+    }
+}
+
+/// Verifies the FECF at the end of a frame, returning the frame without it.
+fn check_fecf(bytes: &[u8]) -> Result<&[u8], CcsdsError> {
+    require(bytes, FECF_LENGTH)?;
+    let (frame, fecf) = bytes.split_at(bytes.len() - FECF_LENGTH);
+    let found = u16::from_be_bytes([fecf[0], fecf[1]]);
+    let expected = crc16_ccitt(frame);
+    if found != expected {
+        return Err(CcsdsError::FecfMismatch { expected, found });
+    }
+    Ok(frame)
+}
+
+impl TelemetryMessage {
+    /// Decodes the payload as a TM transfer frame, verifying its FECF if
+    /// `fecf` is set.
+    pub fn tm_frame(&self, fecf: bool) -> Result<TmFrame, CcsdsError> {
+        TmFrame::decode(&self.payload, fecf)
+    }
+}
@@ -0,0 +1,155 @@
+//! CCSDS Space Packet Protocol (CCSDS 133.0-B) and TM/TC Space Data Link
+//! Protocols (CCSDS 132.0-B, 232.0-B).
+
+use std::collections::HashMap;
+use std::fmt;
+
+mod frame;
+mod packet;
+
+pub use frame::{TcFrame, TmFrame};
+pub use packet::{IDLE_APID, PacketType, SequenceFlags, SpacePacket, TimeCode};
+
+/// Error type for CCSDS encoding and decoding failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum CcsdsError {
+    /// The input is shorter than its headers or declared length
+    TooShort { expected: usize, found: usize },
+    /// The version number field is not 0
+    UnsupportedVersion(u8),
+    /// The declared frame length does not match the frame
+    LengthMismatch { declared: usize, found: usize },
+    /// The frame error control field does not match the frame contents
+    FecfMismatch { expected: u16, found: u16 },
+    /// The APID does not fit in 11 bits
+    InvalidApid(u16),
+    /// The sequence count does not fit in 14 bits
+    InvalidSequenceCount(u16),
+    /// The spacecraft id does not fit in 10 bits
+    InvalidSpacecraftId(u16),
+    /// The virtual channel id is too large for the frame type
+    InvalidVirtualChannel(u8),
+    /// The first header pointer does not fit in 11 bits
+    InvalidFirstHeaderPointer(u16),
+    /// The data field is empty or too long for its length field
+    InvalidDataLength(usize),
+    /// The time code format is not 1–4 coarse and 0–3 fine octets, or the
+    /// time is outside its range
+    InvalidTimeCode,
+}
+
+impl fmt::Display for CcsdsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CcsdsError::*;
+        match self {
+            TooShort { expected, found } => {
+                write!(f, "expected at least {expected} bytes, found {found}")
+            }
+            UnsupportedVersion(version) => write!(f, "unsupported version number {version}"),
+            LengthMismatch { declared, found } => write!(
+                f,
+                "declared length {declared} does not match the {found} bytes found"
+            ),
+            FecfMismatch { expected, found } => write!(
+                f,
+                "frame error control mismatch: expected {expected:#06x}, found {found:#06x}"
+            ),
+            InvalidApid(apid) => write!(f, "APID {apid} does not fit in 11 bits"),
+            InvalidSequenceCount(count) => {
+                write!(f, "sequence count {count} does not fit in 14 bits")
+            }
+            InvalidSpacecraftId(id) => write!(f, "spacecraft id {id} does not fit in 10 bits"),
+            InvalidVirtualChannel(id) => {
+                write!(f, "virtual channel id {id} is too large for the frame type")
+            }
+            InvalidFirstHeaderPointer(pointer) => {
+                write!(f, "first header pointer {pointer} does not fit in 11 bits")
+            }
+            InvalidDataLength(length) => {
+                write!(f, "data field length {length} is empty or too long")
+            }
+            InvalidTimeCode => f.write_str("invalid time code format or time out of range"),
+        }
+    }
+}
+
+impl std::error::Error for CcsdsError {}
+
+/// A discontinuity in a sequence counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceGap {
+    /// APID or virtual channel the counter belongs to.
+    pub channel: u16,
+    /// Count that should have been received.
+    pub expected: u16,
+    /// Count that was received.
+    pub found: u16,
+    /// Number of packets or frames lost, assuming the counter wrapped at
+    /// most once.
+    pub missing: u16,
+}
+
+/// # Sequence Tracker
+///
+/// Detects lost packets or frames from the wrapping sequence counters of
+/// independent channels (APIDs or virtual channels).
+///
+/// ## Example
+/// ```
+/// use rustar_types::codec::{SequenceGap, SequenceTracker, SpacePacket};
+///
+/// let mut tracker = SequenceTracker::new(SpacePacket::SEQUENCE_MODULUS);
+/// assert_eq!(tracker.observe(100, 16382), None);
+/// assert_eq!(tracker.observe(100, 16383), None);
+/// assert_eq!(
+///     tracker.observe(100, 2),
+///     Some(SequenceGap { channel: 100, expected: 0, found: 2, missing: 2 })
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceTracker {
+    modulus: u32,
+    last: HashMap<u16, u16>,
+}
+
+impl SequenceTracker {
+    /// Tracks counters that wrap to zero at `modulus`.
+    pub fn new(modulus: u32) -> Self {
+        SequenceTracker {
+            modulus,
+            last: HashMap::new(),
+        }
+    }
+
+    /// Records the count received on `channel`, returning the gap since the
+    /// previous count on that channel, if any.
+    pub fn observe(&mut self, channel: u16, count: u16) -> Option<SequenceGap> {
+        let previous = self.last.insert(channel, count)?;
+        let expected = ((previous as u32 + 1) % self.modulus) as u16;
+        let missing = (count as u32 + self.modulus - expected as u32) % self.modulus;
+
+        (missing != 0).then_some(SequenceGap {
+            channel,
+            expected,
+            found: count,
+            missing: missing as u16,
+        })
+    }
+
+    /// Forgets the last count of every channel.
+    pub fn reset(&mut self) {
+        self.last.clear();
+    }
+}
+
+/// Fails with [`CcsdsError::TooShort`] unless `bytes` holds `expected`
+/// bytes.
+fn require(bytes: &[u8], expected: usize) -> Result<(), CcsdsError> {
+    if bytes.len() < expected {
+        return Err(CcsdsError::TooShort {
+            expected,
+            found: bytes.len(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{CcsdsError, require};
+use crate::mqtt::telemetry::TelemetryMessage;
+
+/// APID reserved for idle packets.
+pub const IDLE_APID: u16 = 0x7FF;
+/// Length of the packet primary header, in bytes.
+const PRIMARY_HEADER_LENGTH: usize = 6;
+
+/// Whether a packet carries telemetry or a telecommand.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketType {
+    Telemetry,
+    Telecommand,
+}
+
+/// Position of a packet within a segmented user data unit.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceFlags {
+    Continuation,
+    First,
+    Last,
+    Unsegmented,
+}
+
+/// # Space Packet
+///
+/// A CCSDS Space Packet. When `secondary_header` is set, `data` starts with
+/// the mission-defined secondary header, usually a [`TimeCode`].
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::codec::{PacketType, SpacePacket, TimeCode};
+///
+/// let time = Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap();
+/// let code = TimeCode::ccsds(4, 2);
+/// let packet = SpacePacket::new(PacketType::Telemetry, 100, 42, vec![1, 2, 3])
+///     .with_timestamp(&code, time)
+///     .unwrap();
+///
+/// let bytes = packet.encode().unwrap();
+/// let (decoded, length) = SpacePacket::decode(&bytes).unwrap();
+/// assert_eq!(length, bytes.len());
+/// assert_eq!(decoded.apid, 100);
+/// assert_eq!(decoded.timestamp(&code).unwrap(), time);
+/// assert_eq!(decoded.user_data(&code), &[1, 2, 3]);
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq)]
+pub struct SpacePacket {
+    pub packet_type: PacketType,
+    /// Whether `data` starts with a secondary header.
+    pub secondary_header: bool,
+    /// Application process identifier, `0..=2047`.
+    #[schema(example = 100)]
+    pub apid: u16,
+    pub sequence_flags: SequenceFlags,
+    /// Packet sequence count, `0..=16383`.
+    #[schema(example = 42)]
+    pub sequence_count: u16,
+    /// Packet data field, including the secondary header.
+    pub data: Vec<u8>,
+}
+
+/// # CCSDS Unsegmented Time Code
+///
+/// Format of a CUC time code with an implicit P-field: whole seconds since
+/// `epoch` in `coarse_octets` bytes, followed by the binary fraction of a
+/// second in `fine_octets` bytes.
+///
+/// Leap seconds are not counted, so for the TAI-based CCSDS epoch the
+/// decoded times are offset from UTC by the accumulated leap seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeCode {
+    /// Number of coarse time octets, `1..=4`.
+    pub coarse_octets: u8,
+    /// Number of fine time octets, `0..=3`.
+    pub fine_octets: u8,
+    /// Time at which the code is zero.
+    pub epoch: DateTime<Utc>,
+}
+
+impl SpacePacket {
+    /// Sequence counts wrap to zero at this value.
+    pub const SEQUENCE_MODULUS: u32 = 1 << 14;
+
+    /// Builds an unsegmented packet without secondary header.
+    pub fn new(packet_type: PacketType, apid: u16, sequence_count: u16, data: Vec<u8>) -> Self {
+        SpacePacket {
+            packet_type,
+            secondary_header: false,
+            apid,
+            sequence_flags: SequenceFlags::Unsegmented,
+            sequence_count,
+            data,
+        }
+    }
+
+    /// Prepends a time code secondary header to the data field.
+    pub fn with_timestamp(
+        mut self,
+        code: &TimeCode,
+        time: DateTime<Utc>,
+    ) -> Result<Self, CcsdsError> {
+        let mut data = code.encode(time)?;
+        data.append(&mut self.data);
+        self.data = data;
+        self.secondary_header = true;
+        Ok(self)
+    }
+
+    /// Time in the secondary header, if the packet has one in format `code`.
+    pub fn timestamp(&self, code: &TimeCode) -> Option<DateTime<Utc>> {
+        if !self.secondary_header {
+            return None;
+        }
+        code.decode(&self.data).ok()
+    }
+
+    /// Data field after the `code` secondary header, if any.
+    pub fn user_data(&self, code: &TimeCode) -> &[u8] {
+        if self.secondary_header {
+            self.data.get(code.length()..).unwrap_or_default()
+        } else {
+            &self.data
+        }
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.apid == IDLE_APID
+    }
+
+    /// Encodes the primary header followed by the data field.
+    pub fn encode(&self) -> Result<Vec<u8>, CcsdsError> {
+        if self.apid > IDLE_APID {
+            return Err(CcsdsError::InvalidApid(self.apid));
+        }
+        if self.sequence_count as u32 >= Self::SEQUENCE_MODULUS {
+            return Err(CcsdsError::InvalidSequenceCount(self.sequence_count));
+        }
+        if self.data.is_empty() || self.data.len() > 1 << 16 {
+            return Err(CcsdsError::InvalidDataLength(self.data.len()));
+        }
+
+        let identification = ((self.packet_type == PacketType::Telecommand) as u16) << 12
+            | (self.secondary_header as u16) << 11
+            | self.apid;
+        let flags = match self.sequence_flags {
+            SequenceFlags::Continuation => 0,
+            SequenceFlags::First => 1,
+            SequenceFlags::Last => 2,
+            SequenceFlags::Unsegmented => 3,
+        };
+        let sequence = flags << 14 | self.sequence_count;
+        let length = (self.data.len() - 1) as u16;
+
+        let mut bytes = Vec::with_capacity(PRIMARY_HEADER_LENGTH + self.data.len());
+        bytes.extend(identification.to_be_bytes());
+        bytes.extend(sequence.to_be_bytes());
+        bytes.extend(length.to_be_bytes());
+        bytes.extend(&self.data);
+        Ok(bytes)
+    }
+
+    /// Decodes the packet at the start of `bytes`, returning it with its
+    /// encoded length. Any bytes after the packet are ignored.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), CcsdsError> {
+        require(bytes, PRIMARY_HEADER_LENGTH)?;
+
+        let word = |i: usize| u16::from_be_bytes([bytes[i], bytes[i + 1]]);
+        let identification = word(0);
+        let sequence = word(2);
+        let length = PRIMARY_HEADER_LENGTH + word(4) as usize + 1;
+
+        let version = (identification >> 13) as u8;
+        if version != 0 {
+            return Err(CcsdsError::UnsupportedVersion(version));
+        }
+        require(bytes, length)?;
+
+        let packet = SpacePacket {
+            packet_type: if identification & 0x1000 != 0 {
+                PacketType::Telecommand
+            } else {
+                PacketType::Telemetry
+            },
+            secondary_header: identification & 0x0800 != 0,
+            apid: identification & 0x07FF,
+            sequence_flags: match sequence >> 14 {
+                0 => SequenceFlags::Continuation,
+                1 => SequenceFlags::First,
+                2 => SequenceFlags::Last,
+                _ => SequenceFlags::Unsegmented,
+            },
+            sequence_count: sequence & 0x3FFF,
+            data: bytes[PRIMARY_HEADER_LENGTH..length].to_vec(),
+        };
+        Ok((packet, length))
+    }
+
+    /// Decodes consecutive packets filling `bytes`, skipping idle packets.
+    pub fn decode_all(mut bytes: &[u8]) -> Result<Vec<Self>, CcsdsError> {
+        let mut packets = Vec::new();
+        while !bytes.is_empty() {
+            let (packet, length) = SpacePacket::decode(bytes)?;
+            if !packet.is_idle() {
+                packets.push(packet);
+            }
+            bytes = &bytes[length..];
+        }
+        Ok(packets)
+    }
+}
+
+impl TimeCode {
+    /// A CUC format with the CCSDS epoch, 1958-01-01.
+    pub fn ccsds(coarse_octets: u8, fine_octets: u8) -> Self {
+        TimeCode {
+            coarse_octets,
+            fine_octets,
+            epoch: Utc.with_ymd_and_hms(1958, 1, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    /// Encoded length, in bytes.
+    pub fn length(&self) -> usize {
+        (self.coarse_octets + self.fine_octets) as usize
+    }
+
+    fn validate(&self) -> Result<(), CcsdsError> {
+        if !(1..=4).contains(&self.coarse_octets) || self.fine_octets > 3 {
+            return Err(CcsdsError::InvalidTimeCode);
+        }
+        Ok(())
+    }
+
+    pub fn encode(&self, time: DateTime<Utc>) -> Result<Vec<u8>, CcsdsError> {
+        self.validate()?;
+
+        let elapsed = time - self.epoch;
+        let seconds = elapsed.num_seconds();
+        let nanoseconds = (elapsed - Duration::seconds(seconds))
+            .num_nanoseconds()
+            .unwrap();
+        if seconds < 0 || nanoseconds < 0 || seconds >> (8 * self.coarse_octets) != 0 {
+            return Err(CcsdsError::InvalidTimeCode);
+        }
+        let fine_bits = 8 * self.fine_octets as u32;
+        let fine = ((nanoseconds as u128) << fine_bits) / 1_000_000_000;
+
+        let coarse = (seconds as u64).to_be_bytes();
+        let fine = (fine as u32).to_be_bytes();
+        Ok(coarse[8 - self.coarse_octets as usize..]
+            .iter()
+            .chain(&fine[4 - self.fine_octets as usize..])
+            .copied()
+            .collect())
+    }
+
+    /// Decodes the time code at the start of `bytes`.
+    pub fn decode(&self, bytes: &[u8]) -> Result<DateTime<Utc>, CcsdsError> {
+        self.validate()?;
+        require(bytes, self.length())?;
+
+        let (coarse, fine) = bytes[..self.length()].split_at(self.coarse_octets as usize);
+        let number = |bytes: &[u8]| bytes.iter().fold(0u64, |acc, &b| acc << 8 | b as u64);
+        let fine_bits = 8 * self.fine_octets as u32;
+        let nanoseconds = ((number(fine) as u128 * 1_000_000_000) >> fine_bits) as i64;
+
+        Ok(self.epoch
+            + Duration::seconds(number(coarse) as i64)
+            + Duration::nanoseconds(nanoseconds))
+    }
+}
+
+impl TelemetryMessage {
+    /// Decodes the payload as a sequence of Space Packets.
+    pub fn space_packets(&self) -> Result<Vec<SpacePacket>, CcsdsError> {
+        SpacePacket::decode_all(&self.payload)
+    }
+}
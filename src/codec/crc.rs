@@ -0,0 +1,28 @@
+/// CRC-16/CCITT-FALSE (polynomial `0x1021`, initial value `0xFFFF`), as
+/// used by CCSDS frames.
+pub(crate) fn crc16_ccitt(data: &[u8]) -> u16 {
+    data.iter().fold(0xFFFF, |crc, &byte| {
+        (0..8).fold(crc ^ ((byte as u16) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// CRC-16/X.25 (reflected polynomial `0x8408`, initial value and final XOR
+/// `0xFFFF`), the frame check sequence of HDLC and AX.25.
+pub(crate) fn crc16_x25(data: &[u8]) -> u16 {
+    let crc = data.iter().fold(0xFFFF, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u16, |crc, _| {
+            if crc & 0x0001 != 0 {
+                (crc >> 1) ^ 0x8408
+            } else {
+                crc >> 1
+            }
+        })
+    });
+    !crc
+}
@@ -0,0 +1,20 @@
+//! # Link Layer Codecs
+//!
+//! Encoders and decoders for the framing protocols spoken by the satellites
+//! a ground station tracks. They operate on raw byte payloads, such as
+//! [`TelemetryMessage::payload`](crate::mqtt::telemetry::TelemetryMessage)
+//! for downlink and [`Job::uplink`](crate::jobs::Job) for uplink.
+
+mod ax25;
+mod ccsds;
+mod crc;
+
+pub use ax25::{
+    Address, Ax25Error, Ax25Frame, NO_LAYER_3, Repeater, UI_CONTROL, hdlc_decode, hdlc_encode,
+    nrzi_decode, nrzi_encode,
+};
+pub use ccsds::{
+    CcsdsError, IDLE_APID, PacketType, SequenceFlags, SequenceGap, SequenceTracker, SpacePacket,
+    TcFrame, TimeCode, TmFrame,
+};
+pub(crate) use crc::{crc16_ccitt, crc16_x25};
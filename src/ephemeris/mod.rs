@@ -0,0 +1,119 @@
+//! # Ephemeris
+//!
+//! Low-precision Sun and Moon positions, satellite eclipses and optical
+//! pass visibility.
+//!
+//! Positions are geocentric and inertial, in kilometers, referred to the
+//! mean equator and equinox of date, which is close enough to the TEME frame
+//! of [`Sgp4`](crate::sgp4::Sgp4) to be mixed with its states. The models
+//! are accurate to about 0.01° for the Sun and 0.3° for the Moon between
+//! 1950 and 2050, treating UTC as UT1 and TDB.
+
+use chrono::{DateTime, Utc};
+
+use crate::frames::julian_date;
+
+mod shadow;
+mod visibility;
+
+pub use shadow::{EclipseInterval, Illumination, ShadowModel, eclipses};
+pub use visibility::{PassVisibility, classify_pass, sun_elevation};
+
+/// Astronomical unit, in km.
+pub const ASTRONOMICAL_UNIT: f64 = 149_597_870.7;
+
+/// Position of the Sun at `time`, in kilometers.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::ephemeris::{ASTRONOMICAL_UNIT, sun_position};
+///
+/// // Vallado, Fundamentals of Astrodynamics, Example 5-1
+/// let sun = sun_position(Utc.with_ymd_and_hms(2006, 4, 2, 0, 0, 0).unwrap());
+/// let expected = [0.9771945, 0.1924424, 0.0834308];
+/// for axis in 0..3 {
+///     assert!((sun[axis] / ASTRONOMICAL_UNIT - expected[axis]).abs() < 1e-5);
+/// }
+/// ```
+pub fn sun_position(time: DateTime<Utc>) -> [f64; 3] {
+    let t = julian_centuries(time);
+
+    let mean_longitude = 280.460 + 36_000.771 * t;
+    let mean_anomaly = (357.529_109_2 + 35_999.050_34 * t).to_radians();
+    let longitude = (mean_longitude
+        + 1.914_666_471 * mean_anomaly.sin()
+        + 0.019_994_643 * (2.0 * mean_anomaly).sin())
+    .to_radians();
+    let distance = 1.000_140_612
+        - 0.016_708_617 * mean_anomaly.cos()
+        - 0.000_139_589 * (2.0 * mean_anomaly).cos();
+
+    let (sin_obliquity, cos_obliquity) = obliquity(t).sin_cos();
+    let (sin_longitude, cos_longitude) = longitude.sin_cos();
+    let distance = distance * ASTRONOMICAL_UNIT;
+    [
+        distance * cos_longitude,
+        distance * cos_obliquity * sin_longitude,
+        distance * sin_obliquity * sin_longitude,
+    ]
+}
+
+/// Position of the Moon at `time`, in kilometers.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::ephemeris::moon_position;
+///
+/// // Vallado, Fundamentals of Astrodynamics, Example 5-3
+/// let moon = moon_position(Utc.with_ymd_and_hms(1994, 4, 28, 0, 0, 0).unwrap());
+/// let expected = [-134_240.626, -311_571.590, -126_693.785];
+/// for axis in 0..3 {
+///     assert!((moon[axis] - expected[axis]).abs() < 1.0);
+/// }
+/// ```
+pub fn moon_position(time: DateTime<Utc>) -> [f64; 3] {
+    let t = julian_centuries(time);
+    let sin = |degrees: f64| degrees.to_radians().sin();
+    let cos = |degrees: f64| degrees.to_radians().cos();
+
+    let longitude = 218.32 + 481_267.881_3 * t + 6.29 * sin(134.9 + 477_198.85 * t)
+        - 1.27 * sin(259.2 - 413_335.38 * t)
+        + 0.66 * sin(235.7 + 890_534.23 * t)
+        + 0.21 * sin(269.9 + 954_397.70 * t)
+        - 0.19 * sin(357.5 + 35_999.05 * t)
+        - 0.11 * sin(186.6 + 966_404.05 * t);
+    let latitude = 5.13 * sin(93.3 + 483_202.03 * t) + 0.28 * sin(228.2 + 960_400.87 * t)
+        - 0.28 * sin(318.3 + 6_003.18 * t)
+        - 0.17 * sin(217.6 - 407_332.20 * t);
+    let parallax = 0.9508
+        + 0.0518 * cos(134.9 + 477_198.85 * t)
+        + 0.0095 * cos(259.2 - 413_335.38 * t)
+        + 0.0078 * cos(235.7 + 890_534.23 * t)
+        + 0.0028 * cos(269.9 + 954_397.70 * t);
+
+    let distance = EARTH_RADIUS / sin(parallax);
+    let (sin_obliquity, cos_obliquity) = obliquity(t).sin_cos();
+    let (sin_longitude, cos_longitude) = longitude.to_radians().sin_cos();
+    let (sin_latitude, cos_latitude) = latitude.to_radians().sin_cos();
+    [
+        distance * cos_latitude * cos_longitude,
+        distance * (cos_obliquity * cos_latitude * sin_longitude - sin_obliquity * sin_latitude),
+        distance * (sin_obliquity * cos_latitude * sin_longitude + cos_obliquity * sin_latitude),
+    ]
+}
+
+/// Equatorial radius of the Earth used by the ephemeris and shadow models,
+/// in km.
+const EARTH_RADIUS: f64 = 6378.137;
+
+/// Julian centuries elapsed since J2000.0.
+fn julian_centuries(time: DateTime<Utc>) -> f64 {
+    (julian_date(time) - 2_451_545.0) / 36_525.0
+}
+
+/// Mean obliquity of the ecliptic, in radians.
+fn obliquity(t: f64) -> f64 {
+    (23.439_291 - 0.013_004_2 * t).to_radians()
+}
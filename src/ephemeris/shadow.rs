@@ -0,0 +1,217 @@
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{EARTH_RADIUS, sun_position};
+use crate::frames::{dot, norm};
+use crate::jobs::Job;
+use crate::sgp4::{Sgp4, Sgp4Error};
+
+/// Time between illumination samples while searching for eclipses.
+const SEARCH_STEP_SECONDS: i64 = 30;
+/// Precision of the eclipse entry and exit times.
+const TIME_TOLERANCE_MILLISECONDS: i64 = 100;
+
+/// Half-angle of the Earth's umbra cone, in radians (0.264121687°).
+const UMBRA_ANGLE: f64 = 0.004_609_793_064;
+/// Half-angle of the Earth's penumbra cone, in radians (0.269007205°).
+const PENUMBRA_ANGLE: f64 = 0.004_695_061_439;
+
+/// How a satellite is lit by the Sun.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Illumination {
+    /// Fully lit.
+    Sunlit,
+    /// Partially shadowed by the Earth.
+    Penumbra,
+    /// Fully shadowed by the Earth.
+    Umbra,
+}
+
+/// Shape of the Earth's shadow.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShadowModel {
+    /// A cylinder of the Earth's radius behind the Earth. Never reports
+    /// [`Illumination::Penumbra`].
+    Cylindrical,
+    /// Umbra and penumbra cones, accounting for the size of the Sun.
+    #[default]
+    Conical,
+}
+
+impl ShadowModel {
+    /// Illumination of a satellite at `satellite`, with the Sun at `sun`,
+    /// both geocentric and in kilometers.
+    pub fn illumination(self, satellite: [f64; 3], sun: [f64; 3]) -> Illumination {
+        let sun_distance = norm(sun);
+        // Distance behind the Earth along the Sun line
+        let behind = -dot(satellite, sun) / sun_distance;
+        if behind <= 0.0 {
+            return Illumination::Sunlit;
+        }
+        let off_axis = (dot(satellite, satellite) - behind * behind)
+            .max(0.0)
+            .sqrt();
+
+        match self {
+            ShadowModel::Cylindrical if off_axis <= EARTH_RADIUS => Illumination::Umbra,
+            ShadowModel::Cylindrical => Illumination::Sunlit,
+            ShadowModel::Conical => {
+                let penumbra_apex = EARTH_RADIUS / PENUMBRA_ANGLE.sin();
+                let penumbra = PENUMBRA_ANGLE.tan() * (penumbra_apex + behind);
+                if off_axis > penumbra {
+                    return Illumination::Sunlit;
+                }
+                let umbra_apex = EARTH_RADIUS / UMBRA_ANGLE.sin();
+                let umbra = UMBRA_ANGLE.tan() * (umbra_apex - behind);
+                if off_axis <= umbra {
+                    Illumination::Umbra
+                } else {
+                    Illumination::Penumbra
+                }
+            }
+        }
+    }
+}
+
+/// # Eclipse Interval
+///
+/// A time span during which a satellite is in the Earth's penumbra or
+/// umbra. With the conical model an eclipse is usually reported as a
+/// penumbra interval, an umbra interval and another penumbra interval.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct EclipseInterval {
+    /// [`Illumination::Penumbra`] or [`Illumination::Umbra`].
+    pub shadow: Illumination,
+    /// Entry time, or the start of the search if already in shadow.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:03:41Z")]
+    pub start: DateTime<Utc>,
+    /// Exit time, or the end of the search if still in shadow.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:38:02Z")]
+    pub end: DateTime<Utc>,
+}
+
+/// Finds every eclipse of the satellite propagated by `propagator` between
+/// `start` and `end`, with entry and exit times within 0.1 s.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::ephemeris::{Illumination, ShadowModel, eclipses};
+/// use rustar_types::jobs::TleData;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+///
+/// let eclipses = eclipses(&tle.propagator().unwrap(), start, start + Duration::days(1), ShadowModel::Conical).unwrap();
+///
+/// // The ISS enters the Earth's shadow on most of its ~15.5 daily orbits
+/// let umbras: Vec<_> = eclipses.iter().filter(|e| e.shadow == Illumination::Umbra).collect();
+/// assert!((14..=17).contains(&umbras.len()));
+/// for umbra in umbras {
+///     assert!(umbra.end - umbra.start < Duration::minutes(40));
+/// }
+/// ```
+pub fn eclipses(
+    propagator: &Sgp4,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    model: ShadowModel,
+) -> Result<Vec<EclipseInterval>, Sgp4Error> {
+    if start >= end {
+        return Ok(Vec::new());
+    }
+    let illumination = |time: DateTime<Utc>| -> Result<Illumination, Sgp4Error> {
+        let satellite = propagator.propagate(time)?.position;
+        Ok(model.illumination(satellite, sun_position(time)))
+    };
+
+    let mut samples = Vec::new();
+    let mut time = start;
+    while time < end {
+        samples.push((time, illumination(time)?));
+        time += Duration::seconds(SEARCH_STEP_SECONDS);
+    }
+    samples.push((end, illumination(end)?));
+
+    // Times at which the illumination changes, and the new illumination
+    let mut changes = Vec::new();
+    for pair in samples.windows(2) {
+        let ((a, before), (b, after)) = (pair[0], pair[1]);
+        if before == after {
+            continue;
+        }
+        // The satellite may cross both the penumbra and umbra boundaries
+        // between two samples; find each one
+        let mut crossings = Vec::new();
+        for depth in [Illumination::Penumbra, Illumination::Umbra] {
+            if (before >= depth) != (after >= depth) {
+                crossings.push(crossing(&illumination, a, b, depth)?);
+            }
+        }
+        crossings.sort();
+        for (i, &crossing) in crossings.iter().enumerate() {
+            let next = crossings.get(i + 1).copied().unwrap_or(b);
+            let middle = crossing + (next - crossing) / 2;
+            let state = if i + 1 == crossings.len() {
+                after
+            } else {
+                illumination(middle)?
+            };
+            changes.push((crossing.round_subsecs(3), state));
+        }
+    }
+
+    let mut intervals = Vec::new();
+    let mut current = (start, samples[0].1);
+    for (time, state) in changes {
+        if state == current.1 {
+            continue;
+        }
+        if current.1 != Illumination::Sunlit {
+            intervals.push(EclipseInterval {
+                shadow: current.1,
+                start: current.0,
+                end: time,
+            });
+        }
+        current = (time, state);
+    }
+    if current.1 != Illumination::Sunlit {
+        intervals.push(EclipseInterval {
+            shadow: current.1,
+            start: current.0,
+            end,
+        });
+    }
+    Ok(intervals)
+}
+
+/// Bisects the time between `a` and `b` at which the shadow depth crosses
+/// `depth`.
+fn crossing(
+    illumination: &impl Fn(DateTime<Utc>) -> Result<Illumination, Sgp4Error>,
+    mut a: DateTime<Utc>,
+    mut b: DateTime<Utc>,
+    depth: Illumination,
+) -> Result<DateTime<Utc>, Sgp4Error> {
+    let a_deeper = illumination(a)? >= depth;
+    while b - a > Duration::milliseconds(TIME_TOLERANCE_MILLISECONDS) {
+        let middle = a + (b - a) / 2;
+        if (illumination(middle)? >= depth) == a_deeper {
+            a = middle;
+        } else {
+            b = middle;
+        }
+    }
+    Ok(b)
+}
+
+impl Job {
+    /// Eclipses of the satellite during the job window.
+    pub fn eclipses(&self, model: ShadowModel) -> Result<Vec<EclipseInterval>, Sgp4Error> {
+        eclipses(&self.orbit.propagator()?, self.start, self.end, model)
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{Illumination, ShadowModel, sun_position};
+use crate::frames::{Geodetic, norm, teme_to_ecef};
+use crate::passes::Pass;
+use crate::sgp4::{Sgp4, Sgp4Error, StateVector};
+use crate::tracking::sample_times;
+
+/// Time between samples when classifying a pass.
+const CLASSIFY_STEP_SECONDS: i64 = 10;
+
+/// # Pass Visibility
+///
+/// Whether an optical observer can see a satellite during a pass: the
+/// satellite must be sunlit against a dark sky.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassVisibility {
+    /// At some point the satellite is sunlit while the station is in
+    /// darkness.
+    Visible,
+    /// The station is in darkness, but the satellite is in the Earth's
+    /// shadow throughout.
+    Eclipsed,
+    /// The sky at the station is too bright throughout.
+    Daylight,
+}
+
+/// Elevation of the Sun as seen from `observer` at `time`, in degrees.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::ephemeris::sun_elevation;
+/// use rustar_types::frames::Geodetic;
+///
+/// let greenwich = Geodetic::new(51.4769, 0.0, 0.0);
+/// // Noon at the June solstice: 90° - (51.48° - 23.44°)
+/// let noon = sun_elevation(&greenwich, Utc.with_ymd_and_hms(2025, 6, 21, 12, 2, 0).unwrap());
+/// assert!((noon - 61.96).abs() < 0.1);
+///
+/// let midnight = sun_elevation(&greenwich, Utc.with_ymd_and_hms(2025, 6, 21, 0, 0, 0).unwrap());
+/// assert!(midnight < 0.0);
+/// ```
+pub fn sun_elevation(observer: &Geodetic, time: DateTime<Utc>) -> f64 {
+    let sun = StateVector {
+        position: sun_position(time),
+        velocity: [0.0; 3],
+    };
+    let sez = observer.to_sez(teme_to_ecef(&sun, time).position);
+    (sez[2] / norm(sez)).asin().to_degrees()
+}
+
+/// Classifies `pass` of the satellite propagated by `propagator` over
+/// `observer` for optical observation.
+///
+/// The station counts as dark when the Sun is below `twilight` degrees of
+/// elevation, e.g. `-6.0` for civil or `-12.0` for nautical twilight. A
+/// satellite in penumbra counts as sunlit.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::ephemeris::{PassVisibility, classify_pass};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::TleData;
+/// use rustar_types::passes::predict_passes;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let propagator = tle.propagator().unwrap();
+/// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let passes =
+///     predict_passes(&propagator, &buenos_aires, 10.0, start, start + Duration::days(1)).unwrap();
+/// assert!(!passes.is_empty());
+///
+/// let classify = |pass| classify_pass(&propagator, &buenos_aires, pass, -6.0).unwrap();
+/// // Around local midnight the ISS is in the Earth's shadow
+/// assert_eq!(classify(&passes[0]), PassVisibility::Eclipsed);
+/// // In the afternoon the sky is too bright
+/// assert_eq!(classify(passes.last().unwrap()), PassVisibility::Daylight);
+/// ```
+pub fn classify_pass(
+    propagator: &Sgp4,
+    observer: &Geodetic,
+    pass: &Pass,
+    twilight: f64,
+) -> Result<PassVisibility, Sgp4Error> {
+    let times = sample_times(pass.aos, pass.los, Duration::seconds(CLASSIFY_STEP_SECONDS))
+        .unwrap_or_else(|_| vec![pass.aos]);
+
+    let mut dark = false;
+    for time in times {
+        if sun_elevation(observer, time) >= twilight {
+            continue;
+        }
+        dark = true;
+        let satellite = propagator.propagate(time)?.position;
+        let illumination = ShadowModel::Conical.illumination(satellite, sun_position(time));
+        if illumination != Illumination::Umbra {
+            return Ok(PassVisibility::Visible);
+        }
+    }
+
+    Ok(if dark {
+        PassVisibility::Eclipsed
+    } else {
+        PassVisibility::Daylight
+    })
+}
@@ -0,0 +1,90 @@
+use serde_json::{Value, json};
+
+use super::{Footprint, GroundTrack};
+
+/// Writes ground tracks and footprints as a GeoJSON `FeatureCollection`.
+///
+/// Each track is a `MultiLineString` feature with one line per segment, and
+/// each footprint a `Polygon` (or `MultiPolygon`, when cut at the
+/// antimeridian) feature. A `kind` property tells them apart.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::export::{footprints, ground_track, to_geojson};
+/// use rustar_types::jobs::TleData;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let track = ground_track(&tle, start, start + Duration::minutes(90), Duration::seconds(30)).unwrap();
+/// let footprints = footprints(&tle, &[start], 10.0).unwrap();
+///
+/// let geojson = to_geojson(&[track], &footprints);
+///
+/// assert_eq!(geojson["type"], "FeatureCollection");
+/// assert_eq!(geojson["features"][0]["geometry"]["type"], "MultiLineString");
+/// assert_eq!(geojson["features"][1]["properties"]["kind"], "footprint");
+/// ```
+pub fn to_geojson(tracks: &[GroundTrack], footprints: &[Footprint]) -> Value {
+    let features = tracks
+        .iter()
+        .map(track_feature)
+        .chain(footprints.iter().map(footprint_feature))
+        .collect::<Vec<_>>();
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+fn track_feature(track: &GroundTrack) -> Value {
+    let lines = track
+        .segments
+        .iter()
+        .map(|segment| {
+            segment
+                .iter()
+                .map(|point| [point.longitude, point.latitude])
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let points = track.segments.iter().flatten();
+
+    json!({
+        "type": "Feature",
+        "geometry": { "type": "MultiLineString", "coordinates": lines },
+        "properties": {
+            "kind": "ground_track",
+            "name": track.name,
+            "start": points.clone().next().map(|point| point.time),
+            "end": points.last().map(|point| point.time),
+        },
+    })
+}
+
+fn footprint_feature(footprint: &Footprint) -> Value {
+    let geometry = match footprint.polygons.as_slice() {
+        [ring] => json!({ "type": "Polygon", "coordinates": [ring] }),
+        rings => json!({
+            "type": "MultiPolygon",
+            "coordinates": rings.iter().map(|ring| [ring]).collect::<Vec<_>>(),
+        }),
+    };
+
+    json!({
+        "type": "Feature",
+        "geometry": geometry,
+        "properties": {
+            "kind": "footprint",
+            "time": footprint.time,
+            "latitude": footprint.center.latitude,
+            "longitude": footprint.center.longitude,
+            "altitude": footprint.center.altitude,
+            "min_elevation": footprint.min_elevation,
+            "radius": footprint.radius,
+        },
+    })
+}
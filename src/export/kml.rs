@@ -0,0 +1,89 @@
+use std::fmt::Write;
+
+use chrono::SecondsFormat;
+
+use super::{Footprint, GroundTrack};
+
+/// Writes ground tracks and footprints as a KML document named `name`.
+///
+/// Each track is a placemark with one clamped-to-ground line per segment,
+/// and each footprint a time-stamped placemark with one polygon per side of
+/// the antimeridian, so Google Earth's time slider can step through them.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::export::{footprints, ground_track, to_kml};
+/// use rustar_types::jobs::TleData;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let track = ground_track(&tle, start, start + Duration::minutes(90), Duration::seconds(30)).unwrap();
+/// let footprints = footprints(&tle, &[start], 10.0).unwrap();
+///
+/// let kml = to_kml("ISS & friends", &[track], &footprints);
+///
+/// assert!(kml.starts_with("<?xml"));
+/// assert!(kml.contains("<name>ISS &amp; friends</name>"));
+/// assert!(kml.contains("<when>2025-08-24T00:00:00Z</when>"));
+/// ```
+pub fn to_kml(name: &str, tracks: &[GroundTrack], footprints: &[Footprint]) -> String {
+    let mut kml = String::new();
+    kml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    kml.push_str("<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n");
+    let _ = writeln!(kml, "<name>{}</name>", escape(name));
+
+    for track in tracks {
+        kml.push_str("<Placemark>\n");
+        let _ = writeln!(kml, "<name>{}</name>", escape(&track.name));
+        kml.push_str("<MultiGeometry>\n");
+        for segment in &track.segments {
+            kml.push_str("<LineString><tessellate>1</tessellate><coordinates>");
+            let points = segment
+                .iter()
+                .map(|point| [point.longitude, point.latitude]);
+            write_coordinates(&mut kml, points);
+            kml.push_str("</coordinates></LineString>\n");
+        }
+        kml.push_str("</MultiGeometry>\n</Placemark>\n");
+    }
+
+    for footprint in footprints {
+        let time = footprint.time.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        kml.push_str("<Placemark>\n");
+        let _ = writeln!(kml, "<name>Footprint {time}</name>");
+        let _ = writeln!(kml, "<TimeStamp><when>{time}</when></TimeStamp>");
+        kml.push_str("<MultiGeometry>\n");
+        for ring in &footprint.polygons {
+            kml.push_str(
+                "<Polygon><tessellate>1</tessellate><outerBoundaryIs><LinearRing><coordinates>",
+            );
+            write_coordinates(&mut kml, ring.iter().copied());
+            kml.push_str("</coordinates></LinearRing></outerBoundaryIs></Polygon>\n");
+        }
+        kml.push_str("</MultiGeometry>\n</Placemark>\n");
+    }
+
+    kml.push_str("</Document>\n</kml>\n");
+    kml
+}
+
+/// Writes `[longitude, latitude]` pairs as a KML coordinate tuple list.
+fn write_coordinates(kml: &mut String, points: impl Iterator<Item = [f64; 2]>) {
+    for (i, [longitude, latitude]) in points.enumerate() {
+        if i > 0 {
+            kml.push(' ');
+        }
+        let _ = write!(kml, "{longitude},{latitude}");
+    }
+}
+
+/// Escapes the XML special characters of `text`.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
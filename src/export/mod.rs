@@ -0,0 +1,352 @@
+//! # Map Export
+//!
+//! Sub-satellite ground tracks and visibility footprints, written as GeoJSON
+//! `FeatureCollection`s for web maps and as KML documents for Google Earth.
+//!
+//! Geometries are cut at the antimeridian, as RFC 7946 recommends, so that
+//! no line or polygon edge spans more than 180° of longitude.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::frames::{Geodetic, WGS84_A, teme_to_ecef};
+use crate::jobs::{Job, TleData};
+use crate::sgp4::Sgp4;
+use crate::tracking::{TrackingError, sample_times};
+
+mod geojson;
+mod kml;
+
+pub use geojson::to_geojson;
+pub use kml::to_kml;
+
+/// Number of vertices of a footprint circle.
+const FOOTPRINT_VERTICES: usize = 72;
+
+/// # Track Point
+///
+/// Sub-satellite point at a given instant.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct TrackPoint {
+    /// UTC timestamp of the point.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub time: DateTime<Utc>,
+    /// Geodetic latitude, in degrees.
+    #[schema(example = -34.61)]
+    pub latitude: f64,
+    /// Longitude, in degrees within `[-180, 180]`.
+    #[schema(example = -58.38)]
+    pub longitude: f64,
+    /// Height of the satellite above the ellipsoid, in kilometers.
+    #[schema(example = 418.2)]
+    pub altitude: f64,
+}
+
+/// # Ground Track
+///
+/// Path of the sub-satellite point over a time window, as one segment per
+/// crossing of the antimeridian. Segments ending and starting at a crossing
+/// share an interpolated point at longitude 180 and -180 respectively.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct GroundTrack {
+    /// Satellite name.
+    #[schema(example = "ISS (ZARYA)")]
+    pub name: String,
+    pub segments: Vec<Vec<TrackPoint>>,
+}
+
+/// # Footprint
+///
+/// Area of the Earth from which a satellite is seen above a minimum
+/// elevation at a given instant, approximated by a circle on a spherical
+/// Earth.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct Footprint {
+    /// UTC timestamp of the footprint.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub time: DateTime<Utc>,
+    /// Sub-satellite point, with the satellite's altitude.
+    pub center: Geodetic,
+    /// Elevation the footprint is computed for, in degrees.
+    #[schema(example = 10.0)]
+    pub min_elevation: f64,
+    /// Ground distance from the center to the edge, in kilometers.
+    #[schema(example = 1_395.8)]
+    pub radius: f64,
+    /// Outer rings of the footprint as `[longitude, latitude]` pairs, one per
+    /// side of the antimeridian. Each ring is closed (its last point equals
+    /// its first).
+    pub polygons: Vec<Vec<[f64; 2]>>,
+}
+
+/// Computes the ground track of the satellite described by `tle` from
+/// `start` to `end`, every `step`.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::export::ground_track;
+/// use rustar_types::jobs::TleData;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+///
+/// let track = ground_track(&tle, start, start + Duration::hours(3), Duration::seconds(30)).unwrap();
+///
+/// // Two orbits cross the antimeridian twice
+/// assert!((2..=3).contains(&(track.segments.len() - 1)));
+/// for segment in &track.segments {
+///     for pair in segment.windows(2) {
+///         assert!((pair[1].longitude - pair[0].longitude).abs() < 180.0);
+///     }
+/// }
+/// let first = &track.segments[0];
+/// assert_eq!(first.last().unwrap().longitude.abs(), 180.0);
+/// ```
+pub fn ground_track(
+    tle: &TleData,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<GroundTrack, TrackingError> {
+    track(&tle.propagator()?, &tle.tle0, start, end, step)
+}
+
+/// Computes the footprints of the satellite described by `tle` at each of
+/// `times`, for stations with a `min_elevation` mask (degrees).
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::export::footprints;
+/// use rustar_types::jobs::TleData;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let time = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+///
+/// let horizon = footprints(&tle, &[time], 0.0).unwrap();
+/// let mask = footprints(&tle, &[time], 10.0).unwrap();
+///
+/// // About 2200 km to the horizon from the ISS' altitude
+/// assert!((2_000.0..2_400.0).contains(&horizon[0].radius));
+/// assert!(mask[0].radius < horizon[0].radius);
+/// ```
+pub fn footprints(
+    tle: &TleData,
+    times: &[DateTime<Utc>],
+    min_elevation: f64,
+) -> Result<Vec<Footprint>, TrackingError> {
+    footprints_of(&tle.propagator()?, times, min_elevation)
+}
+
+impl Job {
+    /// Ground track of the satellite during the job window, every `step`.
+    pub fn ground_track(&self, step: Duration) -> Result<GroundTrack, TrackingError> {
+        track(
+            &self.orbit.propagator()?,
+            self.orbit.name(),
+            self.start,
+            self.end,
+            step,
+        )
+    }
+
+    /// Footprints of the satellite at each of `times`, for stations with a
+    /// `min_elevation` mask (degrees).
+    pub fn footprints(
+        &self,
+        times: &[DateTime<Utc>],
+        min_elevation: f64,
+    ) -> Result<Vec<Footprint>, TrackingError> {
+        footprints_of(&self.orbit.propagator()?, times, min_elevation)
+    }
+}
+
+/// Sub-satellite point at `time`.
+fn sub_satellite_point(propagator: &Sgp4, time: DateTime<Utc>) -> Result<Geodetic, TrackingError> {
+    let teme = propagator.propagate(time)?;
+    Ok(Geodetic::from_ecef(teme_to_ecef(&teme, time).position))
+}
+
+fn track(
+    propagator: &Sgp4,
+    name: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<GroundTrack, TrackingError> {
+    let mut segments = Vec::new();
+    let mut segment: Vec<TrackPoint> = Vec::new();
+
+    for time in sample_times(start, end, step)? {
+        let location = sub_satellite_point(propagator, time)?;
+        let point = TrackPoint {
+            time,
+            latitude: location.latitude,
+            longitude: location.longitude,
+            altitude: location.altitude,
+        };
+
+        if let Some(&previous) = segment.last()
+            && (point.longitude - previous.longitude).abs() > 180.0
+        {
+            // Unwrap the longitude to find where the track crosses ±180
+            let edge = 180f64.copysign(previous.longitude);
+            let unwrapped = point.longitude + 360f64.copysign(previous.longitude);
+            let t = (edge - previous.longitude) / (unwrapped - previous.longitude);
+            let elapsed = (point.time - previous.time).num_milliseconds() as f64 * t;
+            let crossing = TrackPoint {
+                time: previous.time + Duration::milliseconds(elapsed.round() as i64),
+                latitude: previous.latitude + (point.latitude - previous.latitude) * t,
+                longitude: edge,
+                altitude: previous.altitude + (point.altitude - previous.altitude) * t,
+            };
+            segment.push(crossing);
+            segments.push(std::mem::replace(
+                &mut segment,
+                vec![TrackPoint {
+                    longitude: -edge,
+                    ..crossing
+                }],
+            ));
+        }
+        segment.push(point);
+    }
+    segments.push(segment);
+
+    Ok(GroundTrack {
+        name: name.to_string(),
+        segments,
+    })
+}
+
+fn footprints_of(
+    propagator: &Sgp4,
+    times: &[DateTime<Utc>],
+    min_elevation: f64,
+) -> Result<Vec<Footprint>, TrackingError> {
+    times
+        .iter()
+        .map(|&time| {
+            let center = sub_satellite_point(propagator, time)?;
+            let elevation = min_elevation.to_radians();
+            // Earth central angle between the sub-satellite point and a
+            // station seeing the satellite at `min_elevation`
+            let angle =
+                (WGS84_A * elevation.cos() / (WGS84_A + center.altitude)).acos() - elevation;
+            Ok(Footprint {
+                time,
+                center,
+                min_elevation,
+                radius: WGS84_A * angle,
+                polygons: circle(&center, angle.max(0.0)),
+            })
+        })
+        .collect()
+}
+
+/// Rings of a spherical circle of `angle` radians around `center`, cut at
+/// the antimeridian.
+fn circle(center: &Geodetic, angle: f64) -> Vec<Vec<[f64; 2]>> {
+    let (sin_lat, cos_lat) = center.latitude.to_radians().sin_cos();
+    let (sin_angle, cos_angle) = angle.sin_cos();
+
+    // Counterclockwise vertices, with longitudes unwrapped to be continuous
+    let mut ring: Vec<[f64; 2]> = Vec::with_capacity(FOOTPRINT_VERTICES + 1);
+    for i in 0..FOOTPRINT_VERTICES {
+        let bearing = -std::f64::consts::TAU * i as f64 / FOOTPRINT_VERTICES as f64;
+        let latitude = (sin_lat * cos_angle + cos_lat * sin_angle * bearing.cos()).asin();
+        let offset = (bearing.sin() * sin_angle * cos_lat)
+            .atan2(cos_angle - sin_lat * latitude.sin())
+            .to_degrees();
+        let mut longitude = center.longitude + offset;
+        if let Some(&[previous, _]) = ring.last() {
+            longitude += 360.0 * ((previous - longitude) / 360.0).round();
+        }
+        ring.push([longitude, latitude.to_degrees()]);
+    }
+
+    let winding = ring[0][0] - ring[FOOTPRINT_VERTICES - 1][0];
+    if winding.abs() > 180.0 {
+        return vec![polar_cap(ring, center.latitude.signum())];
+    }
+
+    ring.push(ring[0]);
+    (-1..=1)
+        .filter_map(|turn| {
+            let shift = 360.0 * turn as f64;
+            let east = clip(
+                &ring,
+                |[longitude, _]| longitude - shift >= -180.0,
+                shift - 180.0,
+            );
+            let part = clip(
+                &east,
+                |[longitude, _]| longitude - shift <= 180.0,
+                shift + 180.0,
+            );
+            if part.len() < 4 {
+                return None;
+            }
+            Some(
+                part.into_iter()
+                    .map(|[longitude, latitude]| [longitude - shift, latitude])
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Closes a ring around a pole along the antimeridian and through the
+/// pole. Every meridian crosses such a ring once, so its vertices are in
+/// order of longitude.
+fn polar_cap(mut ring: Vec<[f64; 2]>, pole: f64) -> Vec<[f64; 2]> {
+    for point in &mut ring {
+        point[0] = (point[0] + 180.0).rem_euclid(360.0) - 180.0;
+    }
+    ring.sort_by(|a, b| a[0].total_cmp(&b[0]));
+
+    // Latitude where the ring meets the antimeridian
+    let (first, last) = (ring[0], ring[ring.len() - 1]);
+    let span = first[0] + 360.0 - last[0];
+    let t = if span > 0.0 {
+        (180.0 - last[0]) / span
+    } else {
+        0.0
+    };
+    let edge = last[1] + (first[1] - last[1]) * t;
+
+    let mut cap = vec![[-180.0, 90.0 * pole], [-180.0, edge]];
+    cap.extend(ring);
+    cap.extend([[180.0, edge], [180.0, 90.0 * pole], [-180.0, 90.0 * pole]]);
+    // Eastward along the ring is counterclockwise around the north pole only
+    if pole < 0.0 {
+        cap.reverse();
+    }
+    cap
+}
+
+/// Clips a closed ring to the side of the meridian `longitude` where
+/// `inside` holds (Sutherland–Hodgman).
+fn clip(ring: &[[f64; 2]], inside: impl Fn([f64; 2]) -> bool, longitude: f64) -> Vec<[f64; 2]> {
+    let mut clipped = Vec::new();
+    for pair in ring.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if inside(a) {
+            clipped.push(a);
+        }
+        if inside(a) != inside(b) {
+            let t = (longitude - a[0]) / (b[0] - a[0]);
+            clipped.push([longitude, a[1] + (b[1] - a[1]) * t]);
+        }
+    }
+    if let Some(&first) = clipped.first() {
+        clipped.push(first);
+    }
+    clipped
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::julian_date;
+
+/// Offset between Julian and Modified Julian dates.
+const MJD_OFFSET: f64 = 2_400_000.5;
+
+/// Error type for EOP file loading failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum EopError {
+    /// The file could not be read
+    Io(String),
+    /// A data line does not have the expected columns
+    InvalidLine { line: usize },
+    /// A data line is not later than the one before it
+    Unsorted { line: usize },
+    /// The file has no data lines
+    Empty,
+}
+
+impl fmt::Display for EopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use EopError::*;
+        match self {
+            Io(error) => write!(f, "could not read EOP data: {error}"),
+            InvalidLine { line } => write!(f, "malformed EOP data on line {line}"),
+            Unsorted { line } => write!(
+                f,
+                "EOP data on line {line} is not later than the line before"
+            ),
+            Empty => f.write_str("no EOP data"),
+        }
+    }
+}
+
+impl std::error::Error for EopError {}
+
+/// # Earth Orientation
+///
+/// Earth orientation parameters (EOP) at one instant, as published by the
+/// IERS. The default value applies no corrections.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Default)]
+pub struct EarthOrientation {
+    /// Polar motion along the x axis, in arcseconds.
+    #[schema(example = -0.140682)]
+    pub x_pole: f64,
+    /// Polar motion along the y axis, in arcseconds.
+    #[schema(example = 0.333309)]
+    pub y_pole: f64,
+    /// UT1 − UTC, in seconds.
+    #[schema(example = -0.4399619)]
+    pub ut1_utc: f64,
+    /// Excess length of day, in seconds.
+    #[schema(example = 0.0015563)]
+    pub length_of_day: f64,
+}
+
+impl EarthOrientation {
+    /// UT1 at UTC `time`, for the sidereal angle functions.
+    pub fn ut1(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        time + Duration::nanoseconds((self.ut1_utc * 1e9).round() as i64)
+    }
+}
+
+/// A daily EOP value.
+#[derive(Debug, Clone, Copy)]
+struct EopEntry {
+    mjd: f64,
+    orientation: EarthOrientation,
+    /// TAI − UTC, in seconds, to interpolate across leap seconds.
+    tai_utc: f64,
+}
+
+/// # EOP Table
+///
+/// Daily Earth orientation parameters loaded from a local file in the
+/// CelesTrak/CSSI format (`EOP-All.txt`, `EOP-Last5Years.txt`), and
+/// interpolated linearly between days.
+///
+/// Each data line holds the date, MJD, x and y pole, UT1 − UTC, LOD, the
+/// nutation corrections and TAI − UTC. Header, section marker and comment
+/// lines are skipped.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::EopTable;
+///
+/// let table = EopTable::parse("
+/// VERSION 1.2
+/// BEGIN OBSERVED
+/// 2004 04 06 53101 -0.140682  0.333309 -0.4399619  0.0015563 -0.052195 -0.003875  0.000000  0.000000  32
+/// 2004 04 07 53102 -0.139982  0.332953 -0.4414890  0.0015070 -0.052167 -0.004013  0.000000  0.000000  32
+/// END OBSERVED
+/// ").unwrap();
+///
+/// let noon = Utc.with_ymd_and_hms(2004, 4, 6, 12, 0, 0).unwrap();
+/// let eop = table.at(noon).unwrap();
+/// assert!((eop.x_pole - -0.140332).abs() < 1e-9);
+/// assert!((eop.ut1_utc - -0.44072545).abs() < 1e-9);
+///
+/// assert!(table.at(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()).is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EopTable {
+    entries: Vec<EopEntry>,
+}
+
+impl EopTable {
+    /// Reads and parses the EOP file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, EopError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|error| EopError::Io(error.to_string()))?;
+        EopTable::parse(&text)
+    }
+
+    /// Parses the contents of an EOP file.
+    pub fn parse(text: &str) -> Result<Self, EopError> {
+        let mut entries: Vec<EopEntry> = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            // Data lines start with a four-digit year
+            let is_data = columns
+                .first()
+                .is_some_and(|year| year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()));
+            if !is_data {
+                continue;
+            }
+
+            let entry = parse_entry(&columns).ok_or(EopError::InvalidLine { line: number })?;
+            if entries.last().is_some_and(|last| last.mjd >= entry.mjd) {
+                return Err(EopError::Unsorted { line: number });
+            }
+            entries.push(entry);
+        }
+
+        if entries.is_empty() {
+            return Err(EopError::Empty);
+        }
+        Ok(EopTable { entries })
+    }
+
+    /// Earth orientation at `time`, or `None` if it is outside the table.
+    ///
+    /// UT1 − UTC is interpolated as UT1 − TAI, so that leap seconds between
+    /// two days do not spread over the whole day.
+    pub fn at(&self, time: DateTime<Utc>) -> Option<EarthOrientation> {
+        let mjd = julian_date(time) - MJD_OFFSET;
+        let after = self.entries.partition_point(|entry| entry.mjd <= mjd);
+        if after == 0 {
+            return None;
+        }
+        let before = &self.entries[after - 1];
+        if before.mjd == mjd {
+            return Some(before.orientation);
+        }
+        let after = self.entries.get(after)?;
+
+        let t = (mjd - before.mjd) / (after.mjd - before.mjd);
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        let (a, b) = (&before.orientation, &after.orientation);
+        let ut1_tai = lerp(a.ut1_utc - before.tai_utc, b.ut1_utc - after.tai_utc);
+
+        Some(EarthOrientation {
+            x_pole: lerp(a.x_pole, b.x_pole),
+            y_pole: lerp(a.y_pole, b.y_pole),
+            ut1_utc: ut1_tai + before.tai_utc,
+            length_of_day: lerp(a.length_of_day, b.length_of_day),
+        })
+    }
+
+    /// First and last instants covered by the table.
+    pub fn range(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let time = |mjd: f64| {
+            DateTime::from_timestamp(((mjd - 40_587.0) * 86_400.0).round() as i64, 0)
+                .unwrap_or_default()
+        };
+        // Never empty, see `parse`
+        let first = self.entries.first().map_or(0.0, |entry| entry.mjd);
+        let last = self.entries.last().map_or(0.0, |entry| entry.mjd);
+        (time(first), time(last))
+    }
+}
+
+/// Parses the columns of a data line.
+fn parse_entry(columns: &[&str]) -> Option<EopEntry> {
+    if columns.len() < 13 {
+        return None;
+    }
+    let number = |index: usize| columns[index].parse::<f64>().ok();
+
+    Some(EopEntry {
+        mjd: number(3)?,
+        orientation: EarthOrientation {
+            x_pole: number(4)?,
+            y_pole: number(5)?,
+            ut1_utc: number(6)?,
+            length_of_day: number(7)?,
+        },
+        tai_utc: number(12)?,
+    })
+}
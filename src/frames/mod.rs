@@ -0,0 +1,318 @@
+//! # Coordinate Frames
+//!
+//! Conversions between the TEME frame produced by [`Sgp4`], the Earth-fixed
+//! (ECEF/ITRF) frame, WGS-84 geodetic coordinates and a ground observer's
+//! topocentric horizon.
+//!
+//! Earth-fixed conversions ignore polar motion and approximate UT1 by UTC
+//! unless given an [`EarthOrientation`], which can be loaded from a local
+//! EOP file with [`EopTable`].
+
+use std::f64::consts::TAU;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::sgp4::{Sgp4, Sgp4Error, StateVector, gstime};
+
+mod eop;
+
+pub use eop::{EarthOrientation, EopError, EopTable};
+
+/// WGS-84 equatorial radius, in km.
+pub const WGS84_A: f64 = 6378.137;
+/// WGS-84 flattening.
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// Nominal Earth rotation rate, in rad/s.
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115_146_706_979e-5;
+
+/// # Geodetic Position
+///
+/// A location on or above the WGS-84 ellipsoid, typically a ground station.
+///
+/// ## Example
+/// ```json
+/// {
+///   "latitude": -34.6037,
+///   "longitude": -58.3816,
+///   "altitude": 0.025
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct Geodetic {
+    /// Geodetic latitude, in degrees (positive north).
+    #[schema(example = -34.6037)]
+    pub latitude: f64,
+    /// Longitude, in degrees (positive east).
+    #[schema(example = -58.3816)]
+    pub longitude: f64,
+    /// Height above the WGS-84 ellipsoid, in kilometers.
+    #[schema(example = 0.025)]
+    pub altitude: f64,
+}
+
+/// # Topocentric Observation
+///
+/// Position of a satellite as seen from a ground observer.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Topocentric {
+    /// Azimuth, in degrees clockwise from true north, in `[0, 360)`.
+    pub azimuth: f64,
+    /// Elevation above the local horizon, in degrees.
+    pub elevation: f64,
+    /// Slant range, in kilometers.
+    pub range: f64,
+    /// Range rate, in kilometers per second (positive when receding).
+    pub range_rate: f64,
+}
+
+impl Geodetic {
+    pub fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Geodetic {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Earth-fixed Cartesian position of this location, in kilometers.
+    pub fn to_ecef(&self) -> [f64; 3] {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let (sin_lat, cos_lat) = self.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude.to_radians().sin_cos();
+        let n = WGS84_A / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+
+        [
+            (n + self.altitude) * cos_lat * cos_lon,
+            (n + self.altitude) * cos_lat * sin_lon,
+            (n * (1.0 - e2) + self.altitude) * sin_lat,
+        ]
+    }
+
+    /// The location of an Earth-fixed Cartesian position, in kilometers.
+    ///
+    /// Iterates on the latitude, and is accurate to well under a millimeter
+    /// from the center of the Earth to beyond geosynchronous altitude.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::frames::Geodetic;
+    ///
+    /// // Vallado, Fundamentals of Astrodynamics, Example 3-3
+    /// let location = Geodetic::from_ecef([6524.834, 6862.875, 6448.296]);
+    /// assert!((location.latitude - 34.352496).abs() < 1e-6);
+    /// assert!((location.longitude - 46.446417).abs() < 1e-6);
+    /// assert!((location.altitude - 5085.22).abs() < 1e-2);
+    ///
+    /// let round_trip = Geodetic::from_ecef(location.to_ecef());
+    /// assert!((round_trip.altitude - location.altitude).abs() < 1e-9);
+    /// ```
+    pub fn from_ecef(position: [f64; 3]) -> Self {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let [x, y, z] = position;
+        let p = x.hypot(y);
+
+        let mut latitude = z.atan2(p * (1.0 - e2));
+        let mut altitude = 0.0;
+        for _ in 0..10 {
+            let (sin_lat, cos_lat) = latitude.sin_cos();
+            let n = WGS84_A / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+            // Stable at every latitude, unlike p / cos(latitude) - n
+            altitude = p * cos_lat + z * sin_lat - WGS84_A * WGS84_A / n;
+            let next = z.atan2(p * (1.0 - e2 * n / (n + altitude)));
+            if (next - latitude).abs() < 1e-14 {
+                latitude = next;
+                break;
+            }
+            latitude = next;
+        }
+
+        Geodetic {
+            latitude: latitude.to_degrees(),
+            longitude: y.atan2(x).to_degrees(),
+            altitude,
+        }
+    }
+
+    /// An Earth-fixed position relative to this location, as south, east
+    /// and zenith components (SEZ), in kilometers.
+    pub fn to_sez(&self, position: [f64; 3]) -> [f64; 3] {
+        let rho = sub(position, self.to_ecef());
+        let (sin_lat, cos_lat) = self.latitude.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude.to_radians().sin_cos();
+        [
+            sin_lat * cos_lon * rho[0] + sin_lat * sin_lon * rho[1] - cos_lat * rho[2],
+            -sin_lon * rho[0] + cos_lon * rho[1],
+            cos_lat * cos_lon * rho[0] + cos_lat * sin_lon * rho[1] + sin_lat * rho[2],
+        ]
+    }
+
+    /// An Earth-fixed position relative to this location, as east, north
+    /// and up components (ENU), in kilometers.
+    pub fn to_enu(&self, position: [f64; 3]) -> [f64; 3] {
+        let [south, east, zenith] = self.to_sez(position);
+        [east, -south, zenith]
+    }
+
+    /// Looks at an Earth-fixed satellite state from this location.
+    pub fn look_at(&self, satellite: &StateVector) -> Topocentric {
+        let rho = sub(satellite.position, self.to_ecef());
+        let range = norm(rho);
+        let [south, east, zenith] = self.to_sez(satellite.position);
+
+        Topocentric {
+            azimuth: east.atan2(-south).to_degrees().rem_euclid(360.0),
+            elevation: (zenith / range).asin().to_degrees(),
+            range,
+            range_rate: dot(rho, satellite.velocity) / range,
+        }
+    }
+}
+
+impl Sgp4 {
+    /// Propagates to `time` and returns the satellite as seen by `observer`.
+    pub fn observe(
+        &self,
+        observer: &Geodetic,
+        time: DateTime<Utc>,
+    ) -> Result<Topocentric, Sgp4Error> {
+        self.observe_with(observer, time, &EarthOrientation::default())
+    }
+
+    /// [`Sgp4::observe`], correcting the Earth's orientation with `eop`.
+    pub fn observe_with(
+        &self,
+        observer: &Geodetic,
+        time: DateTime<Utc>,
+        eop: &EarthOrientation,
+    ) -> Result<Topocentric, Sgp4Error> {
+        let teme = self.propagate(time)?;
+        Ok(observer.look_at(&teme_to_itrf(&teme, time, eop)))
+    }
+}
+
+/// Greenwich mean sidereal time (IAU-82) at `time`, in radians.
+///
+/// Pass UT1 (see [`EarthOrientation::ut1`]) for full accuracy; UTC is off
+/// by up to 0.9 seconds of Earth rotation.
+pub fn gmst(time: DateTime<Utc>) -> f64 {
+    gstime(julian_date(time))
+}
+
+/// Earth rotation angle (IAU 2000) at `time`, in radians.
+///
+/// This is the sidereal angle of the CIO-based frames. TEME, the frame of
+/// SGP4, is defined through [`gmst`] instead. As with [`gmst`], pass UT1 for
+/// full accuracy.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::era;
+///
+/// // J2000.0
+/// let epoch = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+/// assert!((era(epoch).to_degrees() - 280.46061837504).abs() < 1e-9);
+/// ```
+pub fn era(time: DateTime<Utc>) -> f64 {
+    let days = julian_date(time) - 2_451_545.0;
+    // The integer part of `days` adds whole turns; keep it out of the sum
+    let turns = days.fract() + 0.779_057_273_264 + 0.002_737_811_911_354_48 * days;
+    (TAU * turns).rem_euclid(TAU)
+}
+
+/// Rotates a TEME state into the Earth-fixed frame, ignoring polar motion
+/// and approximating UT1 by UTC.
+pub fn teme_to_ecef(state: &StateVector, time: DateTime<Utc>) -> StateVector {
+    teme_to_itrf(state, time, &EarthOrientation::default())
+}
+
+/// Rotates a TEME state into the ITRF, correcting for UT1 − UTC, polar
+/// motion and length of day.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::{EarthOrientation, teme_to_itrf};
+/// use rustar_types::sgp4::StateVector;
+///
+/// // Vallado et al., "Revisiting Spacetrack Report #3" (2006), Appendix C
+/// let time = Utc.with_ymd_and_hms(2004, 4, 6, 7, 51, 28).unwrap()
+///     + chrono::Duration::microseconds(386_009);
+/// let teme = StateVector {
+///     position: [5094.18016210, 6127.64465950, 6380.34453270],
+///     velocity: [-4.746131487, 0.785818041, 5.531931288],
+/// };
+/// let eop = EarthOrientation {
+///     x_pole: -0.140682,
+///     y_pole: 0.333309,
+///     ut1_utc: -0.4399619,
+///     length_of_day: 0.0015563,
+/// };
+///
+/// let itrf = teme_to_itrf(&teme, time, &eop);
+/// let expected_position = [-1033.4793830, 7901.2952754, 6380.3565958];
+/// let expected_velocity = [-3.225636520, -2.872451450, 5.531924446];
+/// for axis in 0..3 {
+///     assert!((itrf.position[axis] - expected_position[axis]).abs() < 1e-4);
+///     assert!((itrf.velocity[axis] - expected_velocity[axis]).abs() < 1e-7);
+/// }
+/// ```
+pub fn teme_to_itrf(
+    state: &StateVector,
+    time: DateTime<Utc>,
+    eop: &EarthOrientation,
+) -> StateVector {
+    let (sin_g, cos_g) = gmst(eop.ut1(time)).sin_cos();
+    let rotate = |v: [f64; 3]| {
+        [
+            cos_g * v[0] + sin_g * v[1],
+            -sin_g * v[0] + cos_g * v[1],
+            v[2],
+        ]
+    };
+
+    // Pseudo Earth-fixed frame, before polar motion
+    let position = rotate(state.position);
+    let velocity = rotate(state.velocity);
+    let rotation_rate = EARTH_ROTATION_RATE * (1.0 - eop.length_of_day / 86_400.0);
+    let velocity = [
+        velocity[0] + rotation_rate * position[1],
+        velocity[1] - rotation_rate * position[0],
+        velocity[2],
+    ];
+
+    let (sin_xp, cos_xp) = (eop.x_pole / 3600.0).to_radians().sin_cos();
+    let (sin_yp, cos_yp) = (eop.y_pole / 3600.0).to_radians().sin_cos();
+    let polar_motion = |v: [f64; 3]| {
+        [
+            cos_xp * v[0] + sin_xp * sin_yp * v[1] + sin_xp * cos_yp * v[2],
+            cos_yp * v[1] - sin_yp * v[2],
+            -sin_xp * v[0] + cos_xp * sin_yp * v[1] + cos_xp * cos_yp * v[2],
+        ]
+    };
+
+    StateVector {
+        position: polar_motion(position),
+        velocity: polar_motion(velocity),
+    }
+}
+
+/// Julian date of a UTC time.
+pub(crate) fn julian_date(time: DateTime<Utc>) -> f64 {
+    let seconds = time.timestamp() as f64 + time.timestamp_subsec_nanos() as f64 * 1e-9;
+    seconds / 86_400.0 + 2_440_587.5
+}
+
+pub(crate) fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
@@ -0,0 +1,242 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use super::{ElementsParseError, Job, Omm, OrbitData, TleCatalog, TleData, TleParseError};
+
+/// # Job Builder
+///
+/// Named-field alternative to [`Job::new`], so the many positional arguments
+/// (in particular the two `f64` frequencies) cannot be mixed up.
+///
+/// The orbital data can be given as raw three-line TLE text, as [`TleData`],
+/// looked up in a [`TleCatalog`] or as an [`Omm`].
+/// If no `satellite_id` is set, it is taken from `tle0` or `OBJECT_NAME`, or
+/// from the NORAD catalog number when the name is blank.
+///
+/// All checks are deferred to [`JobBuilder::build`], which returns a
+/// [`JobBuildError`] instead of panicking.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::jobs::Job;
+///
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 9, 19, 12, 15, 0).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(job.satellite_id, "ISS (ZARYA)");
+/// ```
+#[derive(Debug)]
+pub struct JobBuilder {
+    id: u64,
+    satellite_id: Option<String>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    orbit: Option<Result<OrbitData, JobBuildError>>,
+    rx_frequency: Option<f64>,
+    tx_frequency: Option<f64>,
+    uplink: Option<Vec<u8>>,
+}
+
+/// Error type for [`JobBuilder::build`] failures
+#[derive(Debug, Clone)]
+pub enum JobBuildError {
+    /// No start time was given
+    MissingStart,
+    /// No end time was given
+    MissingEnd,
+    /// No TLE or OMM was given
+    MissingTle,
+    /// No receiver frequency was given
+    MissingRxFrequency,
+    /// No transmitter frequency was given
+    MissingTxFrequency,
+    /// `start` is not strictly before `end`
+    InvalidWindow,
+    /// Receiver frequency is not a positive number of Hertz
+    InvalidRxFrequency,
+    /// Transmitter frequency is not a positive number of Hertz
+    InvalidTxFrequency,
+    /// The TLE lines are malformed
+    InvalidTle(TleParseError),
+    /// The TLE lines or OMM are well-formed but don't hold valid orbital
+    /// elements
+    InvalidElements(ElementsParseError),
+    /// The catalog holds no TLE for the satellite
+    NotInCatalog { norad_id: u32 },
+}
+
+impl fmt::Display for JobBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use JobBuildError::*;
+        match self {
+            MissingStart => f.write_str("missing start time"),
+            MissingEnd => f.write_str("missing end time"),
+            MissingTle => f.write_str("missing TLE or OMM"),
+            MissingRxFrequency => f.write_str("missing receiver frequency"),
+            MissingTxFrequency => f.write_str("missing transmitter frequency"),
+            InvalidWindow => f.write_str("start is not before end"),
+            InvalidRxFrequency => f.write_str("receiver frequency is not positive"),
+            InvalidTxFrequency => f.write_str("transmitter frequency is not positive"),
+            InvalidTle(error) => write!(f, "invalid TLE: {error}"),
+            InvalidElements(error) => write!(f, "invalid orbital elements: {error}"),
+            NotInCatalog { norad_id } => write!(f, "no TLE for catalog number {norad_id}"),
+        }
+    }
+}
+
+impl std::error::Error for JobBuildError {}
+
+impl JobBuilder {
+    /// Creates an empty builder for the job with the given `id`.
+    pub fn new(id: u64) -> Self {
+        JobBuilder {
+            id,
+            satellite_id: None,
+            start: None,
+            end: None,
+            orbit: None,
+            rx_frequency: None,
+            tx_frequency: None,
+            uplink: None,
+        }
+    }
+
+    /// Sets the satellite identifier, overriding the one derived from the TLE.
+    pub fn satellite_id(mut self, satellite_id: impl Into<String>) -> Self {
+        self.satellite_id = Some(satellite_id.into());
+        self
+    }
+
+    /// Sets the tracking start time (AOS).
+    pub fn start(mut self, start: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the tracking end time (LOS).
+    pub fn end(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets both ends of the tracking window at once.
+    pub fn window(self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.start(start).end(end)
+    }
+
+    /// Sets the orbital data from an already parsed TLE set.
+    pub fn tle(mut self, tle: TleData) -> Self {
+        self.orbit = Some(Ok(OrbitData::Tle(tle)));
+        self
+    }
+
+    /// Sets the orbital data from raw three-line TLE text.
+    ///
+    /// Parsing errors are reported by [`JobBuilder::build`].
+    pub fn tle_text(mut self, text: impl Into<String>) -> Self {
+        self.orbit = Some(
+            TleData::try_from(text.into())
+                .map(OrbitData::Tle)
+                .map_err(JobBuildError::InvalidTle),
+        );
+        self
+    }
+
+    /// Sets the orbital data to the newest TLE of the satellite with NORAD
+    /// catalog number `norad_id` in `catalog`.
+    ///
+    /// A missing satellite is reported by [`JobBuilder::build`].
+    pub fn catalog_tle(mut self, catalog: &TleCatalog, norad_id: u32) -> Self {
+        self.orbit = Some(
+            catalog
+                .get(norad_id)
+                .cloned()
+                .map(OrbitData::Tle)
+                .ok_or(JobBuildError::NotInCatalog { norad_id }),
+        );
+        self
+    }
+
+    /// Sets the orbital data from an Orbit Mean-Elements Message.
+    pub fn omm(mut self, omm: Omm) -> Self {
+        self.orbit = Some(Ok(OrbitData::Omm(Box::new(omm))));
+        self
+    }
+
+    /// Sets the orbital data from a TLE set or OMM.
+    pub fn orbit(mut self, orbit: OrbitData) -> Self {
+        self.orbit = Some(Ok(orbit));
+        self
+    }
+
+    /// Sets the receiver (downlink) frequency, in Hertz.
+    pub fn rx_frequency(mut self, rx_frequency: f64) -> Self {
+        self.rx_frequency = Some(rx_frequency);
+        self
+    }
+
+    /// Sets the transmitter (uplink) frequency, in Hertz.
+    pub fn tx_frequency(mut self, tx_frequency: f64) -> Self {
+        self.tx_frequency = Some(tx_frequency);
+        self
+    }
+
+    /// Sets the raw bytes to transmit during the pass.
+    pub fn uplink(mut self, uplink: Vec<u8>) -> Self {
+        self.uplink = Some(uplink);
+        self
+    }
+
+    /// Validates the collected fields and builds the [`Job`].
+    pub fn build(self) -> Result<Job, JobBuildError> {
+        let start = self.start.ok_or(JobBuildError::MissingStart)?;
+        let end = self.end.ok_or(JobBuildError::MissingEnd)?;
+        if start >= end {
+            return Err(JobBuildError::InvalidWindow);
+        }
+
+        let rx_frequency = self.rx_frequency.ok_or(JobBuildError::MissingRxFrequency)?;
+        if !(rx_frequency.is_finite() && rx_frequency > 0.0) {
+            return Err(JobBuildError::InvalidRxFrequency);
+        }
+        let tx_frequency = self.tx_frequency.ok_or(JobBuildError::MissingTxFrequency)?;
+        if !(tx_frequency.is_finite() && tx_frequency > 0.0) {
+            return Err(JobBuildError::InvalidTxFrequency);
+        }
+
+        let orbit = self.orbit.ok_or(JobBuildError::MissingTle)??;
+        if let OrbitData::Tle(tle) = &orbit {
+            tle.validate().map_err(JobBuildError::InvalidTle)?;
+        }
+        let elements = orbit.elements().map_err(JobBuildError::InvalidElements)?;
+
+        let satellite_id = match self.satellite_id {
+            Some(satellite_id) => satellite_id,
+            None if !orbit.name().is_empty() => orbit.name().to_string(),
+            None => elements.norad_id.to_string(),
+        };
+
+        Ok(Job {
+            id: self.id,
+            satellite_id,
+            start,
+            end,
+            orbit,
+            rx_frequency,
+            tx_frequency,
+            uplink: self.uplink,
+        })
+    }
+}
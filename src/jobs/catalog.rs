@@ -0,0 +1,254 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::{ElementsParseError, TleData, TleParseError};
+
+/// Error type for TLE catalog parsing failures
+///
+/// `line` is the one-based line number in the input where the offending
+/// element set starts.
+#[derive(Debug, Clone)]
+pub enum CatalogParseError {
+    /// A name line is not followed by two data lines
+    IncompleteEntry { line: usize },
+    /// A line is neither a name nor the first data line of an element set
+    UnexpectedLine { line: usize },
+    /// The data lines of an element set are malformed
+    InvalidTle { line: usize, error: TleParseError },
+    /// The data lines are well-formed but don't hold valid orbital elements
+    InvalidElements {
+        line: usize,
+        error: ElementsParseError,
+    },
+}
+
+impl fmt::Display for CatalogParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CatalogParseError::*;
+        match self {
+            IncompleteEntry { line } => {
+                write!(f, "element set on line {line} is missing its data lines")
+            }
+            UnexpectedLine { line } => write!(f, "line {line} is neither a name nor a data line"),
+            InvalidTle { line, error } => write!(f, "invalid TLE on line {line}: {error}"),
+            InvalidElements { line, error } => {
+                write!(f, "invalid orbital elements on line {line}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CatalogParseError {}
+
+/// # TLE Catalog
+///
+/// A set of TLEs indexed by NORAD catalog number and by name, holding the
+/// newest element set of each satellite.
+///
+/// Parses whole files in the two-line and three-line formats published by
+/// CelesTrak and Space-Track, which may be mixed. Blank lines and CRLF line
+/// endings are ignored, as is the `"0 "` prefix of Space-Track name lines.
+/// Element sets from the two-line format have a blank `tle0` and can only be
+/// looked up by NORAD catalog number.
+///
+/// ## Example
+/// ```
+/// use rustar_types::jobs::TleCatalog;
+///
+/// let catalog = TleCatalog::parse("ISS (ZARYA)\r
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993\r
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648\r
+/// \r
+/// 1 33591U 09005A   25235.51234567  .00000045  00000+0  48123-4 0  9995\r
+/// 2 33591  99.0123 290.4567 0013456 123.4567 236.7890 14.12999999850120\r
+/// 0 ISS (ZARYA)\r
+/// 1 25544U 98067A   25236.50000000  .00011222  00000+0  20339-3 0  9990\r
+/// 2 25544  51.6355 328.4567 0003307 262.0000  98.0000 15.50130000525762\r
+/// ").unwrap();
+///
+/// assert_eq!(catalog.len(), 2);
+/// assert!(catalog.get(33591).is_some());
+///
+/// // The newer of the two ISS element sets is kept
+/// let iss = catalog.get_by_name("ISS (ZARYA)").unwrap();
+/// assert!(iss.tle1.contains("25236.50000000"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TleCatalog {
+    entries: BTreeMap<u32, TleData>,
+    names: HashMap<String, u32>,
+}
+
+impl TleCatalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        TleCatalog::default()
+    }
+
+    /// Parses every element set in `text`.
+    pub fn parse(text: &str) -> Result<Self, CatalogParseError> {
+        let mut catalog = TleCatalog::new();
+
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .peekable();
+
+        while let Some((number, line)) = lines.next() {
+            let (name, tle1) = if is_data_line(line, '1') {
+                (String::new(), line)
+            } else if is_data_line(line, '2') {
+                return Err(CatalogParseError::UnexpectedLine { line: number });
+            } else {
+                match lines.next_if(|(_, next)| is_data_line(next, '1')) {
+                    Some((_, tle1)) => (name_of(line), tle1),
+                    None => return Err(CatalogParseError::IncompleteEntry { line: number }),
+                }
+            };
+            let (_, tle2) = lines
+                .next_if(|(_, next)| is_data_line(next, '2'))
+                .ok_or(CatalogParseError::IncompleteEntry { line: number })?;
+
+            let tle = TleData {
+                tle0: name,
+                tle1: tle1.to_string(),
+                tle2: tle2.to_string(),
+            };
+            tle.validate()
+                .map_err(|error| CatalogParseError::InvalidTle {
+                    line: number,
+                    error,
+                })?;
+            catalog
+                .insert(tle)
+                .map_err(|error| CatalogParseError::InvalidElements {
+                    line: number,
+                    error,
+                })?;
+        }
+
+        Ok(catalog)
+    }
+
+    /// Adds `tle` unless the catalog already holds a newer or equally recent
+    /// element set of the same satellite. Returns whether it was added.
+    ///
+    /// Names are not unique: a name shared by several satellites looks up the
+    /// one inserted last. A newer element set without a name keeps the name
+    /// of the one it replaces.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::jobs::TleCatalog;
+    ///
+    /// let catalog = TleCatalog::parse("DEBRIS
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648
+    /// DEBRIS
+    /// 1 33591U 09005A   25235.51234567  .00000045  00000+0  48123-4 0  9995
+    /// 2 33591  99.0123 290.4567 0013456 123.4567 236.7890 14.12999999850120
+    /// ISS (ZARYA)
+    /// 1 25544U 98067A   25236.50000000  .00011222  00000+0  20339-3 0  9990
+    /// 2 25544  51.6355 328.4567 0003307 262.0000  98.0000 15.50130000525762
+    /// ").unwrap();
+    ///
+    /// // Renaming 25544 leaves the other satellite's name alone
+    /// assert!(catalog.get_by_name("DEBRIS").unwrap().tle1.contains("33591"));
+    /// assert!(catalog.get_by_name("ISS (ZARYA)").unwrap().tle1.contains("25544"));
+    /// ```
+    pub fn insert(&mut self, mut tle: TleData) -> Result<bool, ElementsParseError> {
+        let elements = tle.elements()?;
+        let norad_id = elements.norad_id;
+
+        if let Some(existing) = self.entries.get(&norad_id) {
+            // Entries are only inserted after their elements were parsed
+            if existing.epoch()? >= elements.epoch {
+                return Ok(false);
+            }
+            if tle.tle0.is_empty() {
+                // A two-line set keeps the name, and whatever it looks up
+                tle.tle0 = existing.tle0.clone();
+                self.entries.insert(norad_id, tle);
+                return Ok(true);
+            }
+            // The name may have been taken over by another satellite since
+            if self.names.get(&existing.tle0) == Some(&norad_id) {
+                self.names.remove(&existing.tle0);
+            }
+        }
+
+        if !tle.tle0.is_empty() {
+            self.names.insert(tle.tle0.clone(), norad_id);
+        }
+        self.entries.insert(norad_id, tle);
+        Ok(true)
+    }
+
+    /// Adds every element set of `other`, keeping the newest of each
+    /// satellite.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::jobs::TleCatalog;
+    ///
+    /// let mut catalog = TleCatalog::parse("ISS (ZARYA)
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648
+    /// ").unwrap();
+    /// let update = TleCatalog::parse("1 25544U 98067A   25236.50000000  .00011222  00000+0  20339-3 0  9990
+    /// 2 25544  51.6355 328.4567 0003307 262.0000  98.0000 15.50130000525762
+    /// ").unwrap();
+    ///
+    /// catalog.merge(update);
+    ///
+    /// // The newer two-line set replaces the old one under the same name
+    /// let iss = catalog.get_by_name("ISS (ZARYA)").unwrap();
+    /// assert_eq!(iss.tle0, "ISS (ZARYA)");
+    /// assert!(iss.tle1.contains("25236.50000000"));
+    /// ```
+    pub fn merge(&mut self, other: TleCatalog) {
+        for tle in other.entries.into_values() {
+            // Parsed when it was inserted into `other`
+            let _ = self.insert(tle);
+        }
+    }
+
+    /// The element set of the satellite with NORAD catalog number
+    /// `norad_id`.
+    pub fn get(&self, norad_id: u32) -> Option<&TleData> {
+        self.entries.get(&norad_id)
+    }
+
+    /// The element set whose `tle0` is `name`.
+    pub fn get_by_name(&self, name: &str) -> Option<&TleData> {
+        self.names
+            .get(name.trim())
+            .and_then(|norad_id| self.entries.get(norad_id))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Element sets in order of NORAD catalog number.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &TleData)> {
+        self.entries.iter().map(|(&norad_id, tle)| (norad_id, tle))
+    }
+}
+
+/// Whether `line` looks like data line `number` of an element set.
+fn is_data_line(line: &str, number: char) -> bool {
+    let mut chars = line.chars();
+    line.len() == 69 && chars.next() == Some(number) && chars.next() == Some(' ')
+}
+
+/// Satellite name of a name line, without the Space-Track `"0 "` prefix.
+fn name_of(line: &str) -> String {
+    line.strip_prefix("0 ").unwrap_or(line).trim().to_string()
+}
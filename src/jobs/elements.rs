@@ -0,0 +1,602 @@
+use std::fmt;
+use std::ops::Range;
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{Job, OrbitData, TleData};
+
+// Column layout of the TLE data lines, as zero-based byte ranges.
+// The TLE format documentation uses one-based inclusive columns; e.g.
+// columns 03-07 of line 1 map to `2..7` here.
+pub(crate) const LINE_NUMBER: Range<usize> = 0..2;
+pub(crate) const CATALOG_NUMBER: Range<usize> = 2..7;
+pub(crate) const CLASSIFICATION: Range<usize> = 7..8;
+pub(crate) const INTERNATIONAL_DESIGNATOR: Range<usize> = 9..17;
+pub(crate) const EPOCH_YEAR: Range<usize> = 18..20;
+pub(crate) const EPOCH_DAY: Range<usize> = 20..32;
+pub(crate) const MEAN_MOTION_DOT: Range<usize> = 33..43;
+pub(crate) const MEAN_MOTION_DDOT: Range<usize> = 44..52;
+pub(crate) const DRAG_TERM: Range<usize> = 53..61;
+pub(crate) const EPHEMERIS_TYPE: Range<usize> = 62..63;
+pub(crate) const ELEMENT_SET_NUMBER: Range<usize> = 64..68;
+pub(crate) const INCLINATION: Range<usize> = 8..16;
+pub(crate) const RIGHT_ASCENSION: Range<usize> = 17..25;
+pub(crate) const ECCENTRICITY: Range<usize> = 26..33;
+pub(crate) const ARGUMENT_OF_PERIGEE: Range<usize> = 34..42;
+pub(crate) const MEAN_ANOMALY: Range<usize> = 43..51;
+pub(crate) const MEAN_MOTION: Range<usize> = 52..63;
+pub(crate) const REVOLUTION_NUMBER: Range<usize> = 63..68;
+pub(crate) const CHECKSUM: Range<usize> = 68..69;
+
+/// A TLE field and the check its contents must pass.
+pub(crate) type FieldFormat = (Range<usize>, fn(&str) -> bool);
+
+/// Numeric fields of line 1 and the format each one must follow.
+pub(crate) const LINE1_NUMERIC_FIELDS: &[FieldFormat] = &[
+    (CATALOG_NUMBER, |f| parse_catalog_number(f).is_some()),
+    (EPOCH_YEAR, |f| f.chars().all(|c| c.is_ascii_digit())),
+    (EPOCH_DAY, |f| parse_decimal(f).is_some()),
+    (MEAN_MOTION_DOT, |f| parse_decimal(f).is_some()),
+    (MEAN_MOTION_DDOT, |f| parse_implied_decimal(f).is_some()),
+    (DRAG_TERM, |f| parse_implied_decimal(f).is_some()),
+    (EPHEMERIS_TYPE, |f| f == " " || parse_integer(f).is_some()),
+    (ELEMENT_SET_NUMBER, |f| parse_counter(f).is_some()),
+];
+
+/// Numeric fields of line 2 and the format each one must follow.
+pub(crate) const LINE2_NUMERIC_FIELDS: &[FieldFormat] = &[
+    (CATALOG_NUMBER, |f| parse_catalog_number(f).is_some()),
+    (INCLINATION, |f| parse_decimal(f).is_some()),
+    (RIGHT_ASCENSION, |f| parse_decimal(f).is_some()),
+    (ECCENTRICITY, |f| parse_fraction(f).is_some()),
+    (ARGUMENT_OF_PERIGEE, |f| parse_decimal(f).is_some()),
+    (MEAN_ANOMALY, |f| parse_decimal(f).is_some()),
+    (MEAN_MOTION, |f| parse_decimal(f).is_some()),
+    (REVOLUTION_NUMBER, |f| parse_counter(f).is_some()),
+];
+
+/// # Orbital Elements
+///
+/// Typed SGP4 mean elements parsed from a [`TleData`] set.
+///
+/// Angles are expressed in **degrees** and mean motion in **revolutions per
+/// day**, exactly as they appear in the TLE. No unit conversion is applied,
+/// so the values can be compared against the source lines directly.
+///
+/// ## Example
+/// ```
+/// use rustar_types::jobs::{OrbitalElements, TleData};
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+///
+/// let elements = OrbitalElements::try_from(&tle).unwrap();
+/// assert_eq!(elements.norad_id, 25544);
+/// assert_eq!(elements.international_designator, "98067A");
+/// assert_eq!(elements.inclination, 51.6355);
+/// assert_eq!(elements.eccentricity, 0.0003307);
+/// assert_eq!(elements.revolution_number, 52564);
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct OrbitalElements {
+    /// NORAD catalog number of the satellite. Numbers above 99999 are
+    /// decoded from their Alpha-5 form (see [`TleData::norad_id`]).
+    #[schema(example = 25544)]
+    pub norad_id: u32,
+    /// Security classification of the element set.
+    pub classification: Classification,
+    /// International (COSPAR) designator, e.g. `"98067A"`. Empty if the TLE
+    /// leaves it blank.
+    #[schema(example = "98067A")]
+    pub international_designator: String,
+    /// Epoch of the element set.
+    #[schema(value_type = String, format = "date-time", example = "2025-08-23T18:09:15.082Z")]
+    pub epoch: DateTime<Utc>,
+    /// First time derivative of the mean motion divided by two, in rev/day².
+    pub mean_motion_dot: f64,
+    /// Second time derivative of the mean motion divided by six, in rev/day³.
+    pub mean_motion_ddot: f64,
+    /// B* drag term, in inverse Earth radii.
+    pub drag_term: f64,
+    /// Ephemeris type. Always `0` for publicly distributed element sets.
+    pub ephemeris_type: u8,
+    /// Element set number, incremented by the producer for each new set.
+    pub element_set_number: u16,
+    /// Inclination, in degrees.
+    pub inclination: f64,
+    /// Right ascension of the ascending node, in degrees.
+    pub right_ascension: f64,
+    /// Eccentricity (dimensionless).
+    pub eccentricity: f64,
+    /// Argument of perigee, in degrees.
+    pub argument_of_perigee: f64,
+    /// Mean anomaly, in degrees.
+    pub mean_anomaly: f64,
+    /// Mean motion, in revolutions per day.
+    pub mean_motion: f64,
+    /// Revolution number at epoch.
+    pub revolution_number: u32,
+}
+
+/// Security classification of a TLE (column 08 of line 1).
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    Unclassified,
+    Classified,
+    Secret,
+}
+
+/// # Orbit Regime
+///
+/// Coarse classification of an orbit, which determines how fast its TLEs
+/// lose accuracy: low orbits are dominated by poorly predictable
+/// atmospheric drag.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrbitRegime {
+    /// Period under 128 minutes.
+    LowEarth,
+    /// Between low Earth and geosynchronous orbits.
+    MediumEarth,
+    /// Period of about one sidereal day.
+    Geosynchronous,
+    /// Eccentricity above 0.25, such as Molniya or GTO orbits.
+    HighlyElliptical,
+}
+
+/// Error type for orbital element parsing failures.
+///
+/// Each variant names the TLE field or OMM keyword that could not be
+/// converted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementsParseError {
+    /// Catalog number (line 1, columns 03-07) is not a valid number or
+    /// Alpha-5 number
+    InvalidCatalogNumber,
+    /// Classification (line 1, column 08) is not `U`, `C` or `S`
+    InvalidClassification,
+    /// International designator (line 1, columns 10-17) is malformed
+    InvalidInternationalDesignator,
+    /// Epoch (line 1, columns 19-32) is not a valid year and day of year
+    InvalidEpoch,
+    /// First derivative of mean motion (line 1, columns 34-43) is malformed
+    InvalidMeanMotionDot,
+    /// Second derivative of mean motion (line 1, columns 45-52) is malformed
+    InvalidMeanMotionDdot,
+    /// B* drag term (line 1, columns 54-61) is malformed
+    InvalidDragTerm,
+    /// Ephemeris type (line 1, column 63) is not a digit
+    InvalidEphemerisType,
+    /// Element set number (line 1, columns 65-68) is not a valid number
+    InvalidElementSetNumber,
+    /// Inclination (line 2, columns 09-16) is malformed
+    InvalidInclination,
+    /// Right ascension of the ascending node (line 2, columns 18-25) is malformed
+    InvalidRightAscension,
+    /// Eccentricity (line 2, columns 27-33) is malformed
+    InvalidEccentricity,
+    /// Argument of perigee (line 2, columns 35-42) is malformed
+    InvalidArgumentOfPerigee,
+    /// Mean anomaly (line 2, columns 44-51) is malformed
+    InvalidMeanAnomaly,
+    /// Mean motion (line 2, columns 53-63) is malformed
+    InvalidMeanMotion,
+    /// Revolution number (line 2, columns 64-68) is not a valid number
+    InvalidRevolutionNumber,
+    /// OMM `MEAN_ELEMENT_THEORY` is not `SGP4`
+    UnsupportedMeanElementTheory,
+    /// OMM `REF_FRAME` is not `TEME` or `CENTER_NAME` is not `EARTH`
+    UnsupportedReferenceFrame,
+    /// OMM `TIME_SYSTEM` is not `UTC`
+    UnsupportedTimeSystem,
+}
+
+impl fmt::Display for ElementsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ElementsParseError::*;
+        let message = match self {
+            InvalidCatalogNumber => "invalid catalog number (line 1, columns 03-07)",
+            InvalidClassification => "invalid classification (line 1, column 08)",
+            InvalidInternationalDesignator => {
+                "invalid international designator (line 1, columns 10-17)"
+            }
+            InvalidEpoch => "invalid epoch (line 1, columns 19-32)",
+            InvalidMeanMotionDot => {
+                "invalid first derivative of mean motion (line 1, columns 34-43)"
+            }
+            InvalidMeanMotionDdot => {
+                "invalid second derivative of mean motion (line 1, columns 45-52)"
+            }
+            InvalidDragTerm => "invalid B* drag term (line 1, columns 54-61)",
+            InvalidEphemerisType => "invalid ephemeris type (line 1, column 63)",
+            InvalidElementSetNumber => "invalid element set number (line 1, columns 65-68)",
+            InvalidInclination => "invalid inclination (line 2, columns 09-16)",
+            InvalidRightAscension => {
+                "invalid right ascension of the ascending node (line 2, columns 18-25)"
+            }
+            InvalidEccentricity => "invalid eccentricity (line 2, columns 27-33)",
+            InvalidArgumentOfPerigee => "invalid argument of perigee (line 2, columns 35-42)",
+            InvalidMeanAnomaly => "invalid mean anomaly (line 2, columns 44-51)",
+            InvalidMeanMotion => "invalid mean motion (line 2, columns 53-63)",
+            InvalidRevolutionNumber => "invalid revolution number (line 2, columns 64-68)",
+            UnsupportedMeanElementTheory => "OMM mean element theory is not SGP4",
+            UnsupportedReferenceFrame => "OMM reference frame is not TEME centered on the Earth",
+            UnsupportedTimeSystem => "OMM time system is not UTC",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ElementsParseError {}
+
+impl TryFrom<&TleData> for OrbitalElements {
+    type Error = ElementsParseError;
+
+    /// Parses the fixed-column fields of `tle1` and `tle2` into typed elements.
+    ///
+    /// Only the fields themselves are checked here; line lengths and
+    /// checksums are validated when the [`TleData`] is built.
+    fn try_from(tle: &TleData) -> Result<Self, Self::Error> {
+        use ElementsParseError::*;
+
+        let line1 = tle.tle1.as_str();
+        let line2 = tle.tle2.as_str();
+
+        let norad_id = field(line1, CATALOG_NUMBER)
+            .and_then(parse_catalog_number)
+            .ok_or(InvalidCatalogNumber)?;
+
+        let classification = match field(line1, CLASSIFICATION) {
+            Some("U") => Classification::Unclassified,
+            Some("C") => Classification::Classified,
+            Some("S") => Classification::Secret,
+            _ => return Err(InvalidClassification),
+        };
+
+        let international_designator = field(line1, INTERNATIONAL_DESIGNATOR)
+            .filter(|designator| {
+                designator
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == ' ')
+            })
+            .map(|designator| designator.trim().to_string())
+            .ok_or(InvalidInternationalDesignator)?;
+
+        let epoch = parse_epoch(line1).ok_or(InvalidEpoch)?;
+
+        let mean_motion_dot = field(line1, MEAN_MOTION_DOT)
+            .and_then(parse_decimal)
+            .ok_or(InvalidMeanMotionDot)?;
+        let mean_motion_ddot = field(line1, MEAN_MOTION_DDOT)
+            .and_then(parse_implied_decimal)
+            .ok_or(InvalidMeanMotionDdot)?;
+        let drag_term = field(line1, DRAG_TERM)
+            .and_then(parse_implied_decimal)
+            .ok_or(InvalidDragTerm)?;
+
+        let ephemeris_type = match field(line1, EPHEMERIS_TYPE) {
+            // Some producers leave the ephemeris type blank.
+            Some(" ") => 0,
+            Some(digit) => parse_integer(digit).ok_or(InvalidEphemerisType)? as u8,
+            None => return Err(InvalidEphemerisType),
+        };
+        let element_set_number = field(line1, ELEMENT_SET_NUMBER)
+            .and_then(parse_counter)
+            .ok_or(InvalidElementSetNumber)? as u16;
+
+        let inclination = field(line2, INCLINATION)
+            .and_then(parse_decimal)
+            .ok_or(InvalidInclination)?;
+        let right_ascension = field(line2, RIGHT_ASCENSION)
+            .and_then(parse_decimal)
+            .ok_or(InvalidRightAscension)?;
+        let eccentricity = field(line2, ECCENTRICITY)
+            .and_then(parse_fraction)
+            .ok_or(InvalidEccentricity)?;
+        let argument_of_perigee = field(line2, ARGUMENT_OF_PERIGEE)
+            .and_then(parse_decimal)
+            .ok_or(InvalidArgumentOfPerigee)?;
+        let mean_anomaly = field(line2, MEAN_ANOMALY)
+            .and_then(parse_decimal)
+            .ok_or(InvalidMeanAnomaly)?;
+        let mean_motion = field(line2, MEAN_MOTION)
+            .and_then(parse_decimal)
+            .ok_or(InvalidMeanMotion)?;
+        let revolution_number = field(line2, REVOLUTION_NUMBER)
+            .and_then(parse_counter)
+            .ok_or(InvalidRevolutionNumber)?;
+
+        Ok(OrbitalElements {
+            norad_id,
+            classification,
+            international_designator,
+            epoch,
+            mean_motion_dot,
+            mean_motion_ddot,
+            drag_term,
+            ephemeris_type,
+            element_set_number,
+            inclination,
+            right_ascension,
+            eccentricity,
+            argument_of_perigee,
+            mean_anomaly,
+            mean_motion,
+            revolution_number,
+        })
+    }
+}
+
+impl OrbitalElements {
+    /// Orbit regime of these elements.
+    pub fn regime(&self) -> OrbitRegime {
+        if self.eccentricity > 0.25 {
+            OrbitRegime::HighlyElliptical
+        } else if self.mean_motion >= 11.25 {
+            OrbitRegime::LowEarth
+        } else if (0.9..=1.1).contains(&self.mean_motion) {
+            OrbitRegime::Geosynchronous
+        } else {
+            OrbitRegime::MediumEarth
+        }
+    }
+}
+
+impl TleData {
+    /// Parses this TLE set into typed [`OrbitalElements`].
+    pub fn elements(&self) -> Result<OrbitalElements, ElementsParseError> {
+        OrbitalElements::try_from(self)
+    }
+
+    /// NORAD catalog number of this TLE set.
+    ///
+    /// Catalog numbers above 99999 are written in Alpha-5: the first digit
+    /// is replaced by a letter standing for 10 to 33, skipping `I` and `O`
+    /// to avoid confusion with digits.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::jobs::TleData;
+    ///
+    /// let tle = TleData::try_from("
+    /// 1 E2345U 25001A   25235.50000000  .00000000  00000+0  00000+0 0  9992
+    /// 2 E2345  53.0000 100.0000 0001000  90.0000 270.0000 15.00000000    11".to_string()).unwrap();
+    /// assert_eq!(tle.norad_id().unwrap(), 142345);
+    /// ```
+    pub fn norad_id(&self) -> Result<u32, ElementsParseError> {
+        field(&self.tle1, CATALOG_NUMBER)
+            .and_then(parse_catalog_number)
+            .ok_or(ElementsParseError::InvalidCatalogNumber)
+    }
+
+    /// Epoch of this TLE set.
+    pub fn epoch(&self) -> Result<DateTime<Utc>, ElementsParseError> {
+        Ok(self.elements()?.epoch)
+    }
+
+    /// Age of this TLE set at `time`; negative if `time` is before the
+    /// epoch.
+    ///
+    /// ## Example
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use rustar_types::jobs::TleData;
+    ///
+    /// let tle = TleData::try_from("ISS (ZARYA)
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+    ///
+    /// let time = Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap();
+    /// assert!(tle.age_at(time).unwrap() > Duration::days(26));
+    /// ```
+    pub fn age_at(&self, time: DateTime<Utc>) -> Result<Duration, ElementsParseError> {
+        Ok(time - self.epoch()?)
+    }
+}
+
+impl OrbitData {
+    /// Converts the orbital data into typed [`OrbitalElements`].
+    pub fn elements(&self) -> Result<OrbitalElements, ElementsParseError> {
+        match self {
+            OrbitData::Tle(tle) => tle.elements(),
+            OrbitData::Omm(omm) => omm.elements(),
+        }
+    }
+
+    /// Epoch of the orbital data.
+    pub fn epoch(&self) -> Result<DateTime<Utc>, ElementsParseError> {
+        Ok(self.elements()?.epoch)
+    }
+
+    /// Age of the orbital data at `time`; negative if `time` is before the
+    /// epoch.
+    pub fn age_at(&self, time: DateTime<Utc>) -> Result<Duration, ElementsParseError> {
+        Ok(time - self.epoch()?)
+    }
+}
+
+impl Job {
+    /// Age of the job's TLE or OMM at the start of its window.
+    pub fn tle_age(&self) -> Result<Duration, ElementsParseError> {
+        self.orbit.age_at(self.start)
+    }
+}
+
+/// Checks the modulo-10 checksum in column 69 of a TLE line.
+///
+/// The checksum is the sum of all digits in columns 1-68, counting each
+/// minus sign as 1 and ignoring every other character.
+pub(crate) fn has_valid_checksum(line: &str) -> bool {
+    let (Some(body), Some(expected)) = (line.get(..CHECKSUM.start), field(line, CHECKSUM)) else {
+        return false;
+    };
+    expected
+        .parse::<u32>()
+        .is_ok_and(|digit| digit == checksum(body))
+}
+
+/// Modulo-10 checksum of the first 68 columns of a TLE line.
+pub(crate) fn checksum(body: &str) -> u32 {
+    let sum: u32 = body
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            c => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    sum % 10
+}
+
+/// Returns the column range of the first field that doesn't follow its
+/// expected format, if any.
+pub(crate) fn first_malformed_field(line: &str, fields: &[FieldFormat]) -> Option<Range<usize>> {
+    fields
+        .iter()
+        .find(|(columns, is_valid)| !field(line, columns.clone()).is_some_and(is_valid))
+        .map(|(columns, _)| columns.clone())
+}
+
+/// Returns the given column range of a TLE line, if the line is long enough.
+pub(crate) fn field(line: &str, columns: Range<usize>) -> Option<&str> {
+    line.get(columns)
+}
+
+/// Parses an unsigned integer field, allowing blank padding (`"  813"`).
+pub(crate) fn parse_integer(field: &str) -> Option<u32> {
+    let digits = field.trim();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Parses a catalog number field, either numeric (`"25544"`, `"  813"`) or
+/// Alpha-5 (`"E2345"` is 142345).
+pub(crate) fn parse_catalog_number(field: &str) -> Option<u32> {
+    let mut chars = field.chars();
+    let first = chars.next()?;
+    if !first.is_ascii_uppercase() {
+        return parse_integer(field);
+    }
+    let rest = chars.as_str();
+    if rest.len() != 4 || !rest.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let prefix = ALPHA5_LETTERS.find(first)? as u32 + 10;
+    Some(prefix * 10_000 + rest.parse::<u32>().ok()?)
+}
+
+/// Formats a catalog number as the five-character field of a TLE, using
+/// Alpha-5 above 99999. Numbers above 339999 cannot be written.
+pub(crate) fn format_catalog_number(norad_id: u32) -> Option<String> {
+    match norad_id {
+        0..=99_999 => Some(format!("{norad_id:05}")),
+        100_000..=339_999 => {
+            let letter = ALPHA5_LETTERS
+                .chars()
+                .nth((norad_id / 10_000 - 10) as usize)?;
+            Some(format!("{letter}{:04}", norad_id % 10_000))
+        }
+        _ => None,
+    }
+}
+
+/// Alpha-5 letters, standing for 10 to 33.
+const ALPHA5_LETTERS: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Parses a counter field (element set or revolution number), which some
+/// producers leave blank. A blank counter parses as zero.
+pub(crate) fn parse_counter(field: &str) -> Option<u32> {
+    if field.trim().is_empty() {
+        Some(0)
+    } else {
+        parse_integer(field)
+    }
+}
+
+/// Parses a decimal field with an optional sign and an optional leading
+/// zero (`" .00011222"`, `"-.00000205"`, `" 51.6355"`).
+pub(crate) fn parse_decimal(field: &str) -> Option<f64> {
+    let number = field.trim();
+    let unsigned = number.strip_prefix(['-', '+']).unwrap_or(number);
+    let mut parts = unsigned.splitn(2, '.');
+    let integer = parts.next()?;
+    let fraction = parts.next().unwrap_or("");
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .chars()
+        .chain(fraction.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Parses a field with an implied leading decimal point (`"0003307"` is
+/// `0.0003307`).
+pub(crate) fn parse_fraction(field: &str) -> Option<f64> {
+    let digits = field.trim();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    format!("0.{digits}").parse().ok()
+}
+
+/// Parses a field in the TLE "assumed decimal point" exponential notation,
+/// where `" 20339-3"` is `0.20339e-3` and `"-11606-4"` is `-0.11606e-4`.
+pub(crate) fn parse_implied_decimal(field: &str) -> Option<f64> {
+    let number = field.trim();
+    let split = number.len().checked_sub(2)?;
+    let (mantissa, exponent) = (number.get(..split)?, number.get(split..)?);
+
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut exponent_chars = exponent.chars();
+    let exponent_sign = match exponent_chars.next()? {
+        '-' => -1,
+        '+' | ' ' => 1,
+        _ => return None,
+    };
+    let exponent_value = exponent_chars.next()?.to_digit(10)? as i32;
+
+    let mantissa: f64 = format!("0.{digits}").parse().ok()?;
+    Some(sign * mantissa * 10f64.powi(exponent_sign * exponent_value))
+}
+
+/// Parses the two-digit epoch year and fractional day of year of line 1.
+///
+/// Years 57-99 map to 1957-1999 and 00-56 to 2000-2056, following the
+/// convention used since the first satellite launch.
+fn parse_epoch(line1: &str) -> Option<DateTime<Utc>> {
+    let year = field(line1, EPOCH_YEAR)?;
+    if !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let year: i32 = year.parse().ok()?;
+    let year = if year < 57 { 2000 + year } else { 1900 + year };
+
+    let day = parse_decimal(field(line1, EPOCH_DAY)?)?;
+    let year_start = NaiveDate::from_yo_opt(year, 1)?.and_hms_opt(0, 0, 0)?;
+    let days_in_year = if NaiveDate::from_yo_opt(year, 366).is_some() {
+        366.0
+    } else {
+        365.0
+    };
+    if !(1.0..days_in_year + 1.0).contains(&day) {
+        return None;
+    }
+
+    let offset = Duration::nanoseconds(((day - 1.0) * 86_400e9).round() as i64);
+    Some((year_start + offset).and_utc())
+}
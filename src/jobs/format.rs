@@ -0,0 +1,207 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, Timelike};
+
+use super::elements;
+use super::{Classification, OrbitalElements, TleData};
+
+/// Error type for TLE formatting failures
+///
+/// `columns` uses the same one-based inclusive numbering as
+/// [`TleParseError`](super::TleParseError).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TleFormatError {
+    /// The catalog number is above 339999, the largest Alpha-5 number
+    CatalogNumberOutOfRange,
+    /// The international designator is longer than 8 characters
+    InternationalDesignatorTooLong,
+    /// The epoch is outside 1957-2056, the range of two-digit TLE years
+    EpochOutOfRange,
+    /// A value of line 1 does not fit in its field
+    Tle1ValueOutOfRange { columns: RangeInclusive<usize> },
+    /// A value of line 2 does not fit in its field
+    Tle2ValueOutOfRange { columns: RangeInclusive<usize> },
+}
+
+impl fmt::Display for TleFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TleFormatError::*;
+        match self {
+            CatalogNumberOutOfRange => f.write_str("catalog number is above 339999"),
+            InternationalDesignatorTooLong => {
+                f.write_str("international designator is longer than 8 characters")
+            }
+            EpochOutOfRange => f.write_str("epoch is outside 1957-2056"),
+            Tle1ValueOutOfRange { columns } => write!(
+                f,
+                "value does not fit in TLE line 1 (columns {:02}-{:02})",
+                columns.start(),
+                columns.end()
+            ),
+            Tle2ValueOutOfRange { columns } => write!(
+                f,
+                "value does not fit in TLE line 2 (columns {:02}-{:02})",
+                columns.start(),
+                columns.end()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TleFormatError {}
+
+impl TleData {
+    /// Writes `elements` as a TLE set named `name`.
+    ///
+    /// Catalog numbers above 99999 are written in Alpha-5. Revolution and
+    /// element set numbers wrap around, as they do in published TLEs.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::jobs::TleData;
+    ///
+    /// let tle = TleData::try_from("ISS (ZARYA)
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+    ///
+    /// let mut elements = tle.elements().unwrap();
+    /// let written = TleData::from_elements("ISS (ZARYA)", &elements).unwrap();
+    /// assert_eq!(written.tle1, tle.tle1);
+    /// assert_eq!(written.tle2, tle.tle2);
+    ///
+    /// elements.norad_id = 270_001;
+    /// let written = TleData::from_elements("", &elements).unwrap();
+    /// assert!(written.tle1.starts_with("1 T0001U"));
+    /// assert_eq!(written.norad_id().unwrap(), 270_001);
+    /// ```
+    pub fn from_elements(
+        name: impl Into<String>,
+        elements: &OrbitalElements,
+    ) -> Result<Self, TleFormatError> {
+        use TleFormatError::*;
+
+        let catalog_number =
+            elements::format_catalog_number(elements.norad_id).ok_or(CatalogNumberOutOfRange)?;
+        if elements.international_designator.len() > 8 {
+            return Err(InternationalDesignatorTooLong);
+        }
+
+        let epoch = elements.epoch;
+        if !(1957..=2056).contains(&epoch.year()) {
+            return Err(EpochOutOfRange);
+        }
+        let day = epoch.ordinal() as f64
+            + epoch.num_seconds_from_midnight() as f64 / 86_400.0
+            + epoch.nanosecond() as f64 / 86_400e9;
+
+        let line1_field = |columns: RangeInclusive<usize>| Tle1ValueOutOfRange { columns };
+        let line2_field = |columns: RangeInclusive<usize>| Tle2ValueOutOfRange { columns };
+
+        let classification = match elements.classification {
+            Classification::Unclassified => 'U',
+            Classification::Classified => 'C',
+            Classification::Secret => 'S',
+        };
+        let mean_motion_dot =
+            format_decimal(elements.mean_motion_dot).ok_or(line1_field(34..=43))?;
+        let mean_motion_ddot =
+            format_implied_decimal(elements.mean_motion_ddot).ok_or(line1_field(45..=52))?;
+        let drag_term = format_implied_decimal(elements.drag_term).ok_or(line1_field(54..=61))?;
+        if elements.ephemeris_type > 9 {
+            return Err(line1_field(63..=63));
+        }
+
+        let tle1 = with_checksum(format!(
+            "1 {catalog_number}{classification} {:<8} {:02}{day:012.8} {mean_motion_dot} {mean_motion_ddot} {drag_term} {} {:>4}",
+            elements.international_designator,
+            epoch.year() % 100,
+            elements.ephemeris_type,
+            elements.element_set_number % 10_000,
+        ));
+
+        let inclination = format_angle(elements.inclination).ok_or(line2_field(9..=16))?;
+        let right_ascension = format_angle(elements.right_ascension).ok_or(line2_field(18..=25))?;
+        let eccentricity = (elements.eccentricity * 1e7).round();
+        if !(0.0..1e7).contains(&eccentricity) {
+            return Err(line2_field(27..=33));
+        }
+        let argument_of_perigee =
+            format_angle(elements.argument_of_perigee).ok_or(line2_field(35..=42))?;
+        let mean_anomaly = format_angle(elements.mean_anomaly).ok_or(line2_field(44..=51))?;
+        let mean_motion = format!("{:11.8}", elements.mean_motion);
+        if mean_motion.len() != 11 || elements.mean_motion < 0.0 {
+            return Err(line2_field(53..=63));
+        }
+
+        let tle2 = with_checksum(format!(
+            "2 {catalog_number} {inclination} {right_ascension} {:07} {argument_of_perigee} {mean_anomaly} {mean_motion}{:>5}",
+            eccentricity as u32,
+            elements.revolution_number % 100_000,
+        ));
+
+        Ok(TleData {
+            tle0: name.into(),
+            tle1,
+            tle2,
+        })
+    }
+}
+
+/// Appends the modulo-10 checksum to the first 68 columns of a TLE line.
+fn with_checksum(line: String) -> String {
+    let digit = elements::checksum(&line);
+    format!("{line}{digit}")
+}
+
+/// Formats an angle in degrees as `"ddd.dddd"`.
+fn format_angle(degrees: f64) -> Option<String> {
+    let text = format!("{degrees:8.4}");
+    (degrees >= 0.0 && text.len() == 8).then_some(text)
+}
+
+/// Formats a decimal below one with a sign and no leading zero
+/// (`" .00011222"`, `"-.00000205"`).
+fn format_decimal(value: f64) -> Option<String> {
+    let text = format!("{:.8}", value.abs());
+    let fraction = text.strip_prefix('0')?;
+    let sign = if value < 0.0 && fraction != ".00000000" {
+        '-'
+    } else {
+        ' '
+    };
+    Some(format!("{sign}{fraction}"))
+}
+
+/// Formats a value in the TLE "assumed decimal point" exponential notation
+/// (`0.20339e-3` is `" 20339-3"`).
+fn format_implied_decimal(value: f64) -> Option<String> {
+    if !value.is_finite() {
+        return None;
+    }
+    let magnitude = value.abs();
+    let mut exponent = if magnitude == 0.0 {
+        0
+    } else {
+        magnitude.log10().floor() as i32 + 1
+    };
+    let mut mantissa = (magnitude / 10f64.powi(exponent) * 1e5).round() as u32;
+    if mantissa >= 100_000 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    // Too small to be written: round to zero
+    if exponent < -9 || mantissa == 0 {
+        return Some(" 00000+0".to_string());
+    }
+    if exponent > 9 {
+        return None;
+    }
+
+    let sign = if value < 0.0 { '-' } else { ' ' };
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+    Some(format!(
+        "{sign}{mantissa:05}{exponent_sign}{}",
+        exponent.unsigned_abs()
+    ))
+}
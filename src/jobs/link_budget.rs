@@ -0,0 +1,289 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::Job;
+use crate::frames::Geodetic;
+use crate::tracking::{SPEED_OF_LIGHT, TrackingError, sample_times};
+
+/// Boltzmann's constant, in dBW/K/Hz.
+const BOLTZMANN: f64 = -228.6;
+/// Elevation below which the atmospheric loss stops growing, in degrees.
+/// The cosecant model diverges at the horizon.
+const MIN_ATMOSPHERE_ELEVATION: f64 = 2.0;
+/// Loss between opposite circular polarizations, in dB. Ideally infinite,
+/// limited in practice by the antennas' axial ratios.
+const CROSS_POLARIZATION_LOSS: f64 = 20.0;
+
+/// # Polarization
+///
+/// Polarization of an antenna.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarization {
+    Linear,
+    RightHandCircular,
+    LeftHandCircular,
+}
+
+impl Polarization {
+    /// Mismatch loss between a transmitting and a receiving antenna, in dB.
+    ///
+    /// Linear antennas on a satellite are rarely aligned with the ground
+    /// station's, and Faraday rotation turns the wave anyway, so two linear
+    /// antennas are assumed to lose 3 dB on average, as a linear and a
+    /// circular antenna do.
+    pub fn mismatch_loss(self, other: Polarization) -> f64 {
+        use Polarization::*;
+        match (self, other) {
+            (RightHandCircular, RightHandCircular) | (LeftHandCircular, LeftHandCircular) => 0.0,
+            (RightHandCircular, LeftHandCircular) | (LeftHandCircular, RightHandCircular) => {
+                CROSS_POLARIZATION_LOSS
+            }
+            _ => 3.0,
+        }
+    }
+}
+
+/// # Satellite Transmitter
+///
+/// Downlink transmitter of a satellite.
+///
+/// Example JSON:
+/// ```json
+/// { "eirp": -3.0, "polarization": "Linear" }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct SatelliteTransmitter {
+    /// Effective isotropic radiated power towards the station, in dBW.
+    #[schema(example = -3.0)]
+    pub eirp: f64,
+    pub polarization: Polarization,
+}
+
+/// # Station Receiver
+///
+/// Receiving chain of a ground station.
+///
+/// Example JSON:
+/// ```json
+/// { "g_over_t": -30.0, "polarization": "RightHandCircular", "line_loss": 1.0 }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct StationReceiver {
+    /// Figure of merit of the antenna and receiver, in dB/K.
+    #[schema(example = -30.0)]
+    pub g_over_t: f64,
+    pub polarization: Polarization,
+    /// Losses not included in `g_over_t` (pointing, cables, connectors), in
+    /// dB.
+    #[schema(example = 1.0)]
+    pub line_loss: f64,
+}
+
+/// # Modulation
+///
+/// Data rate and demodulator requirement of a downlink.
+///
+/// Example JSON:
+/// ```json
+/// { "data_rate": 9600.0, "required_eb_n0": 14.0 }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct Modulation {
+    /// Bit rate, in bit/s.
+    #[schema(example = 9600.0)]
+    pub data_rate: f64,
+    /// Eb/N0 needed for the target bit error rate, in dB.
+    #[schema(example = 14.0)]
+    pub required_eb_n0: f64,
+}
+
+/// # Link Budget
+///
+/// Everything besides the geometry needed to tell whether a downlink
+/// closes.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct LinkBudget {
+    pub transmitter: SatelliteTransmitter,
+    pub receiver: StationReceiver,
+    pub modulation: Modulation,
+    /// Atmospheric loss at the zenith, in dB. Lower elevations lose
+    /// proportionally to the air mass crossed.
+    #[schema(example = 0.5)]
+    pub zenith_atmospheric_loss: f64,
+}
+
+/// # Link Sample
+///
+/// Downlink budget at a given instant of a pass.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "time": "2025-09-19T12:00:00Z",
+///   "elevation": 32.5,
+///   "range": 768.3,
+///   "path_loss": 133.4,
+///   "atmospheric_loss": 0.93,
+///   "eb_n0": 20.8,
+///   "margin": 6.8
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct LinkSample {
+    /// UTC timestamp of the sample.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub time: DateTime<Utc>,
+    /// Elevation of the satellite, in degrees.
+    #[schema(example = 32.5)]
+    pub elevation: f64,
+    /// Slant range, in kilometers.
+    #[schema(example = 768.3)]
+    pub range: f64,
+    /// Free-space path loss, in dB.
+    #[schema(example = 133.4)]
+    pub path_loss: f64,
+    /// Atmospheric loss, in dB.
+    #[schema(example = 0.93)]
+    pub atmospheric_loss: f64,
+    /// Received Eb/N0, in dB.
+    #[schema(example = 20.8)]
+    pub eb_n0: f64,
+    /// Received minus required Eb/N0, in dB.
+    #[schema(example = 6.8)]
+    pub margin: f64,
+}
+
+/// # Link Report
+///
+/// Downlink margin along a job window.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct LinkReport {
+    /// Samples with the satellite above the horizon, in time order.
+    pub samples: Vec<LinkSample>,
+    /// From the first to the last sample with a non-negative margin, or
+    /// `None` if the link never closes.
+    pub closing_window: Option<LinkWindow>,
+}
+
+/// Time span during which a downlink closes.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct LinkWindow {
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:02:30Z")]
+    pub start: DateTime<Utc>,
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:09:45Z")]
+    pub end: DateTime<Utc>,
+}
+
+impl LinkBudget {
+    /// Downlink budget on `frequency` (Hz) for a satellite at `range` (km)
+    /// and `elevation` (degrees), at `time`.
+    pub fn sample(
+        &self,
+        time: DateTime<Utc>,
+        frequency: f64,
+        range: f64,
+        elevation: f64,
+    ) -> LinkSample {
+        let wavelength = SPEED_OF_LIGHT * 1e3 / frequency;
+        let path_loss = 20.0 * (4.0 * std::f64::consts::PI * range * 1e3 / wavelength).log10();
+        let air_mass = 1.0 / elevation.max(MIN_ATMOSPHERE_ELEVATION).to_radians().sin();
+        let atmospheric_loss = self.zenith_atmospheric_loss * air_mass;
+
+        let c_n0 = self.transmitter.eirp
+            - path_loss
+            - atmospheric_loss
+            - self
+                .transmitter
+                .polarization
+                .mismatch_loss(self.receiver.polarization)
+            - self.receiver.line_loss
+            + self.receiver.g_over_t
+            - BOLTZMANN;
+        let eb_n0 = c_n0 - 10.0 * self.modulation.data_rate.log10();
+
+        LinkSample {
+            time,
+            elevation,
+            range,
+            path_loss,
+            atmospheric_loss,
+            eb_n0,
+            margin: eb_n0 - self.modulation.required_eb_n0,
+        }
+    }
+}
+
+impl Job {
+    /// Computes the downlink budget on `rx_frequency` for the whole
+    /// `start`..`end` window, every `step`, as received at `observer`.
+    ///
+    /// ## Example
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use rustar_types::frames::Geodetic;
+    /// use rustar_types::jobs::{
+    ///     Job, LinkBudget, Modulation, Polarization, SatelliteTransmitter, StationReceiver,
+    /// };
+    ///
+    /// let job = Job::builder(12345)
+    ///     .tle_text("ISS (ZARYA)
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+    ///     .window(
+    ///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 36, 18).unwrap(),
+    ///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 47, 14).unwrap(),
+    ///     )
+    ///     .rx_frequency(145_800_000.0)
+    ///     .tx_frequency(437_500_000.0)
+    ///     .build()
+    ///     .unwrap();
+    /// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+    ///
+    /// // A half-watt transmitter heard by a small Yagi at 9600 bit/s
+    /// let budget = LinkBudget {
+    ///     transmitter: SatelliteTransmitter { eirp: -3.0, polarization: Polarization::Linear },
+    ///     receiver: StationReceiver {
+    ///         g_over_t: -30.0,
+    ///         polarization: Polarization::RightHandCircular,
+    ///         line_loss: 1.0,
+    ///     },
+    ///     modulation: Modulation { data_rate: 9600.0, required_eb_n0: 14.0 },
+    ///     zenith_atmospheric_loss: 0.5,
+    /// };
+    ///
+    /// let report = job.link_budget(&budget, &buenos_aires, Duration::seconds(5)).unwrap();
+    ///
+    /// // The link only closes in the middle of the pass
+    /// let window = report.closing_window.unwrap();
+    /// assert!(job.start < window.start && window.end < job.end);
+    /// assert!(report.samples[0].margin < 0.0);
+    /// ```
+    pub fn link_budget(
+        &self,
+        budget: &LinkBudget,
+        observer: &Geodetic,
+        step: Duration,
+    ) -> Result<LinkReport, TrackingError> {
+        let propagator = self.orbit.propagator()?;
+
+        let mut samples = Vec::new();
+        for time in sample_times(self.start, self.end, step)? {
+            let look = propagator.observe(observer, time)?;
+            if look.elevation >= 0.0 {
+                samples.push(budget.sample(time, self.rx_frequency, look.range, look.elevation));
+            }
+        }
+
+        let mut closing = samples.iter().filter(|sample| sample.margin >= 0.0);
+        let closing_window = closing.next().map(|first| LinkWindow {
+            start: first.time,
+            end: closing.next_back().unwrap_or(first).time,
+        });
+
+        Ok(LinkReport {
+            samples,
+            closing_window,
+        })
+    }
+}
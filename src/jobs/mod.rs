@@ -1,9 +1,34 @@
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-// TODO: use sgp4 elements instead of TLE DATA
-// TODO: builder interface from TLE data and parses to elements
+mod builder;
+mod catalog;
+mod elements;
+mod format;
+mod link_budget;
+mod omm;
+mod status;
+mod validation;
+
+pub use builder::{JobBuildError, JobBuilder};
+pub use catalog::{CatalogParseError, TleCatalog};
+pub use elements::{Classification, ElementsParseError, OrbitRegime, OrbitalElements};
+pub use format::TleFormatError;
+pub use link_budget::{
+    LinkBudget, LinkReport, LinkSample, LinkWindow, Modulation, Polarization, SatelliteTransmitter,
+    StationReceiver,
+};
+pub use omm::{Omm, OmmParseError};
+pub use status::{
+    FailureReason, JobFailure, JobHistory, JobStatus, JobStatusUpdate, JobTransitionError,
+};
+pub use validation::{
+    JobValidator, JobViolation, JobWarning, StalenessPolicy, StalenessThresholds, ValidationReport,
+};
 
 /// # Job
 ///
@@ -13,7 +38,8 @@ use utoipa::ToSchema;
 /// - **Job identification**: Unique identifier (`id`)
 /// - **Satellite identification**: ID of the satellite (`satellite_id`)
 /// - **Time window**: When tracking should start and end (`start`, `end`)
-/// - **Satellite orbital data**: Two-Line Element set (`tle`)
+/// - **Satellite orbital data**: Two-Line Element set (`tle`) or Orbit
+///   Mean-Elements Message (`omm`)
 /// - **Transceiver frequencies**: Downlink (`rx_frequency`) and uplink (`tx_frequency`)
 /// - **Uplink data**: Optional data to transmit to the satellite (`uplink`)
 ///
@@ -39,6 +65,7 @@ use utoipa::ToSchema;
 /// - `id` must be a **unique 64-bit unsigned integer** identifying this job.
 /// - `satellite_id` is the identifier of the satellite being tracked.
 /// - `start` and `end` must be **UTC timestamps** in ISO-8601 format. (Use https://www.utctime.net/ for getting the current UTC timestamp.)
+/// - Exactly one of `tle` and `omm` must be given (see [`OrbitData`]).
 /// - `tle1` and `tle2` **must be exactly 69 characters long** with valid checksums.
 /// - `rx_frequency` and `tx_frequency` are expressed in **Hertz**.
 /// - `uplink` is **optional** and contains raw bytes to transmit to the satellite during the pass.
@@ -77,7 +104,8 @@ pub struct Job {
     #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:15:00Z")]
     pub end: DateTime<Utc>,
 
-    /// Orbital data (Two-Line Element set) for the satellite to be tracked.
+    /// Orbital data for the satellite to be tracked, serialized as either a
+    /// `tle` or an `omm` field.
     ///
     /// - `tle0`: Human-readable satellite name or catalog identifier.
     /// - `tle1`: First TLE line (exactly 69 characters).
@@ -85,20 +113,14 @@ pub struct Job {
     ///
     /// Example:
     /// ```json
-    /// {
+    /// "tle": {
     ///   "tle0": "ISS (ZARYA)",
     ///   "tle1": "1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993",
     ///   "tle2": "2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648"
     /// }
     /// ```
-    #[schema(
-        example = json!({
-            "tle0": "ISS (ZARYA)",
-            "tle1": "1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993",
-            "tle2": "2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648"
-        })
-    )]
-    pub tle: TleData,
+    #[serde(flatten)]
+    pub orbit: OrbitData,
 
     /// **Receiver frequency** in Hertz (Hz).
     ///
@@ -125,26 +147,15 @@ pub struct Job {
     /// This field contains raw bytes that will be transmitted during the pass.
     /// If `None`, the ground station will only receive data (downlink only).
     ///
+    /// Base64 strings are also accepted, and written with
+    /// [`ContentType::Base64Json`](crate::mqtt::wire::ContentType).
+    ///
     /// Example: `[72, 101, 108, 108, 111]` (ASCII for "Hello")
+    #[serde(default, with = "crate::mqtt::wire::bytes::option")]
     #[schema(example = json!([72, 101, 108, 108, 111]))]
     pub uplink: Option<Vec<u8>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct JobStatusUpdate {
-    pub timestamp: DateTime<Utc>,
-    pub status: JobStatus,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub enum JobStatus {
-    Received,
-    Scheduled,
-    Started,
-    Completed,
-    Error,
-}
-
 /// # Two-Line Element (TLE) Data
 ///
 /// Represents the standard orbital elements used to define a satellite's orbit.
@@ -158,7 +169,7 @@ pub enum JobStatus {
 /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
 /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648
 /// ```
-#[derive(Serialize, Deserialize, ToSchema, Debug)]
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone)]
 pub struct TleData {
     /// Satellite name or catalog ID (first line of a TLE set)
     #[schema(example = "ISS (ZARYA)")]
@@ -171,7 +182,132 @@ pub struct TleData {
     pub tle2: String,
 }
 
+/// # Orbit Data
+///
+/// Orbital data of a job, as a TLE set or an OMM.
+///
+/// In a [`Job`] it is serialized as a `tle` or an `omm` field, so jobs
+/// written before OMM support are still valid.
+///
+/// ## Example
+/// ```
+/// use rustar_types::jobs::{Job, OrbitData};
+///
+/// let json = r#"{
+///   "id": 12345,
+///   "satellite_id": "ISS (ZARYA)",
+///   "start": "2025-09-19T12:00:00Z",
+///   "end": "2025-09-19T12:15:00Z",
+///   "omm": {
+///     "OBJECT_NAME": "ISS (ZARYA)",
+///     "OBJECT_ID": "1998-067A",
+///     "EPOCH": "2025-08-23T18:09:15.082",
+///     "MEAN_MOTION": 15.50129787,
+///     "ECCENTRICITY": 0.0003307,
+///     "INCLINATION": 51.6355,
+///     "RA_OF_ASC_NODE": 332.1708,
+///     "ARG_OF_PERICENTER": 260.2831,
+///     "MEAN_ANOMALY": 99.7785,
+///     "NORAD_CAT_ID": 25544,
+///     "BSTAR": 0.00020339,
+///     "MEAN_MOTION_DOT": 0.00011222
+///   },
+///   "rx_frequency": 145800000,
+///   "tx_frequency": 437500000
+/// }"#;
+///
+/// let job: Job = serde_json::from_str(json).unwrap();
+/// assert!(matches!(job.orbit, OrbitData::Omm(_)));
+/// assert_eq!(job.orbit.elements().unwrap().norad_id, 25544);
+/// assert!(job.tle().is_none());
+///
+/// // A job must carry exactly one of them
+/// let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+/// value["tle"] = serde_json::json!({
+///     "tle0": "ISS (ZARYA)",
+///     "tle1": "1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993",
+///     "tle2": "2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648"
+/// });
+/// let both = serde_json::from_value::<Job>(value.clone()).unwrap_err();
+/// assert!(both.to_string().contains("both `tle` and `omm`"));
+///
+/// value.as_object_mut().unwrap().remove("tle");
+/// value.as_object_mut().unwrap().remove("omm");
+/// let neither = serde_json::from_value::<Job>(value).unwrap_err();
+/// assert!(neither.to_string().contains("missing field `tle` or `omm`"));
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone)]
+#[serde(rename_all = "lowercase", try_from = "OrbitFields")]
+pub enum OrbitData {
+    /// Two-Line Element set.
+    #[schema(
+        example = json!({
+            "tle0": "ISS (ZARYA)",
+            "tle1": "1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993",
+            "tle2": "2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648"
+        })
+    )]
+    Tle(TleData),
+    /// Orbit Mean-Elements Message.
+    Omm(Box<Omm>),
+}
+
+/// Fields of a serialized [`OrbitData`], to reject messages with both or
+/// neither.
+#[derive(Deserialize)]
+struct OrbitFields {
+    tle: Option<TleData>,
+    omm: Option<Box<Omm>>,
+}
+
+impl TryFrom<OrbitFields> for OrbitData {
+    type Error = &'static str;
+
+    fn try_from(fields: OrbitFields) -> Result<Self, Self::Error> {
+        match (fields.tle, fields.omm) {
+            (Some(tle), None) => Ok(OrbitData::Tle(tle)),
+            (None, Some(omm)) => Ok(OrbitData::Omm(omm)),
+            (Some(_), Some(_)) => Err("orbit data has both `tle` and `omm`"),
+            (None, None) => Err("missing field `tle` or `omm`"),
+        }
+    }
+}
+
+impl From<TleData> for OrbitData {
+    fn from(tle: TleData) -> Self {
+        OrbitData::Tle(tle)
+    }
+}
+
+impl From<Omm> for OrbitData {
+    fn from(omm: Omm) -> Self {
+        OrbitData::Omm(Box::new(omm))
+    }
+}
+
+impl OrbitData {
+    /// Satellite name: `tle0` or `OBJECT_NAME`.
+    pub fn name(&self) -> &str {
+        match self {
+            OrbitData::Tle(tle) => &tle.tle0,
+            OrbitData::Omm(omm) => &omm.object_name,
+        }
+    }
+
+    /// The TLE set, if the orbital data is one.
+    pub fn tle(&self) -> Option<&TleData> {
+        match self {
+            OrbitData::Tle(tle) => Some(tle),
+            OrbitData::Omm(_) => None,
+        }
+    }
+}
+
 /// Error type for TLE parsing failures
+///
+/// Variants that point at a specific part of a line carry the offending
+/// `columns`, using the one-based inclusive numbering of the TLE format
+/// documentation (e.g. `69..=69` is the checksum digit).
 #[derive(Debug, Clone)]
 pub enum TleParseError {
     /// Not enough lines in the input (expected 3)
@@ -180,8 +316,48 @@ pub enum TleParseError {
     InvalidTle1Length,
     /// TLE line 2 doesn't have the correct length (expected 69 characters)
     InvalidTle2Length,
+    /// TLE line 1 doesn't start with `"1 "`
+    InvalidTle1LineNumber { columns: RangeInclusive<usize> },
+    /// TLE line 2 doesn't start with `"2 "`
+    InvalidTle2LineNumber { columns: RangeInclusive<usize> },
+    /// TLE line 1 modulo-10 checksum doesn't match its last digit
+    InvalidTle1Checksum { columns: RangeInclusive<usize> },
+    /// TLE line 2 modulo-10 checksum doesn't match its last digit
+    InvalidTle2Checksum { columns: RangeInclusive<usize> },
+    /// A numeric field of TLE line 1 is malformed
+    InvalidTle1Field { columns: RangeInclusive<usize> },
+    /// A numeric field of TLE line 2 is malformed
+    InvalidTle2Field { columns: RangeInclusive<usize> },
+    /// The catalog numbers of lines 1 and 2 differ
+    CatalogNumberMismatch { columns: RangeInclusive<usize> },
+}
+
+impl fmt::Display for TleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TleParseError::*;
+        let (message, columns) = match self {
+            InsufficientLines => return f.write_str("expected 3 TLE lines"),
+            InvalidTle1Length => return f.write_str("TLE line 1 is not 69 characters long"),
+            InvalidTle2Length => return f.write_str("TLE line 2 is not 69 characters long"),
+            InvalidTle1LineNumber { columns } => ("TLE line 1 does not start with \"1 \"", columns),
+            InvalidTle2LineNumber { columns } => ("TLE line 2 does not start with \"2 \"", columns),
+            InvalidTle1Checksum { columns } => ("TLE line 1 checksum mismatch", columns),
+            InvalidTle2Checksum { columns } => ("TLE line 2 checksum mismatch", columns),
+            InvalidTle1Field { columns } => ("malformed field in TLE line 1", columns),
+            InvalidTle2Field { columns } => ("malformed field in TLE line 2", columns),
+            CatalogNumberMismatch { columns } => ("TLE catalog numbers differ", columns),
+        };
+        write!(
+            f,
+            "{message} (columns {:02}-{:02})",
+            columns.start(),
+            columns.end()
+        )
+    }
 }
 
+impl std::error::Error for TleParseError {}
+
 impl TryFrom<String> for TleData {
     type Error = TleParseError;
 
@@ -192,9 +368,13 @@ impl TryFrom<String> for TleData {
     /// - Line 1: First TLE data line (exactly 69 characters)
     /// - Line 2: Second TLE data line (exactly 69 characters)
     ///
+    /// Both data lines must start with their line number, end with a valid
+    /// modulo-10 checksum, carry the same catalog number and have well-formed
+    /// numeric fields.
+    ///
     /// # Example
     /// ```
-    /// use rustar_types::jobs::TleData;
+    /// use rustar_types::jobs::{TleData, TleParseError};
     /// use std::convert::TryFrom;
     ///
     /// let tle_string = "ISS (ZARYA)
@@ -202,6 +382,16 @@ impl TryFrom<String> for TleData {
     /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string();
     ///
     /// let tle_data = TleData::try_from(tle_string).unwrap();
+    ///
+    /// // Same set with the last digit of line 2 altered
+    /// let corrupted = "ISS (ZARYA)
+    /// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+    /// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525649".to_string();
+    ///
+    /// assert!(matches!(
+    ///     TleData::try_from(corrupted),
+    ///     Err(TleParseError::InvalidTle2Checksum { columns }) if columns == (69..=69)
+    /// ));
     /// ```
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let lines: Vec<&str> = value.lines().collect();
@@ -214,26 +404,103 @@ impl TryFrom<String> for TleData {
         let tle1 = lines[1].trim().to_string();
         let tle2 = lines[2].trim().to_string();
 
+        let tle_data = TleData { tle0, tle1, tle2 };
+        tle_data.validate()?;
+
+        Ok(tle_data)
+    }
+}
+
+impl TleData {
+    /// Checks both data lines with the same rules as [`TleData::try_from`].
+    ///
+    /// Useful for sets that were deserialized or built field by field rather
+    /// than parsed from text.
+    pub fn validate(&self) -> Result<(), TleParseError> {
         // Validate TLE line lengths
-        if tle1.len() != 69 {
+        if self.tle1.len() != 69 {
             return Err(TleParseError::InvalidTle1Length);
         }
 
-        if tle2.len() != 69 {
+        if self.tle2.len() != 69 {
             return Err(TleParseError::InvalidTle2Length);
         }
 
-        Ok(TleData { tle0, tle1, tle2 })
+        validate_lines(&self.tle1, &self.tle2)
+    }
+}
+
+/// Validates the contents of both TLE data lines.
+fn validate_lines(tle1: &str, tle2: &str) -> Result<(), TleParseError> {
+    if !tle1.starts_with("1 ") {
+        return Err(TleParseError::InvalidTle1LineNumber {
+            columns: columns(elements::LINE_NUMBER),
+        });
+    }
+    if !tle2.starts_with("2 ") {
+        return Err(TleParseError::InvalidTle2LineNumber {
+            columns: columns(elements::LINE_NUMBER),
+        });
+    }
+
+    if !elements::has_valid_checksum(tle1) {
+        return Err(TleParseError::InvalidTle1Checksum {
+            columns: columns(elements::CHECKSUM),
+        });
+    }
+    if !elements::has_valid_checksum(tle2) {
+        return Err(TleParseError::InvalidTle2Checksum {
+            columns: columns(elements::CHECKSUM),
+        });
+    }
+
+    if let Some(range) = elements::first_malformed_field(tle1, elements::LINE1_NUMERIC_FIELDS) {
+        return Err(TleParseError::InvalidTle1Field {
+            columns: columns(range),
+        });
+    }
+    if let Some(range) = elements::first_malformed_field(tle2, elements::LINE2_NUMERIC_FIELDS) {
+        return Err(TleParseError::InvalidTle2Field {
+            columns: columns(range),
+        });
+    }
+
+    if tle1.get(elements::CATALOG_NUMBER) != tle2.get(elements::CATALOG_NUMBER) {
+        return Err(TleParseError::CatalogNumberMismatch {
+            columns: columns(elements::CATALOG_NUMBER),
+        });
     }
+
+    Ok(())
+}
+
+/// Converts a zero-based byte range into one-based inclusive TLE columns.
+fn columns(range: Range<usize>) -> RangeInclusive<usize> {
+    range.start + 1..=range.end
 }
 
 impl Job {
+    /// Starts building a job with the given `id`. See [`JobBuilder`].
+    pub fn builder(id: u64) -> JobBuilder {
+        JobBuilder::new(id)
+    }
+
+    /// The job's TLE set, or `None` if it carries an OMM.
+    pub fn tle(&self) -> Option<&TleData> {
+        self.orbit.tle()
+    }
+
+    /// Creates a job from all of its fields, without any checks.
+    ///
+    /// Prefer [`Job::builder`], which names every field, parses TLE text and
+    /// validates the job.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: u64,
         satellite_id: impl Into<String>,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
-        tle: TleData,
+        orbit: impl Into<OrbitData>,
         rx_frequency: f64,
         tx_frequency: f64,
         uplink: Option<Vec<u8>>,
@@ -243,7 +510,7 @@ impl Job {
             satellite_id: satellite_id.into(),
             start,
             end,
-            tle,
+            orbit: orbit.into(),
             rx_frequency,
             tx_frequency,
             uplink,
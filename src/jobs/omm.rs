@@ -0,0 +1,493 @@
+use std::fmt;
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{Classification, ElementsParseError, OrbitalElements, TleData};
+
+/// Error type for OMM parsing failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum OmmParseError {
+    /// A KVN line is neither blank, a comment nor a `KEYWORD = value` pair
+    InvalidLine { line: usize },
+    /// A KVN keyword appears more than once
+    DuplicateKeyword { keyword: String },
+    /// A mandatory keyword is missing or a value is malformed
+    InvalidMessage(String),
+}
+
+impl fmt::Display for OmmParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use OmmParseError::*;
+        match self {
+            InvalidLine { line } => write!(f, "line {line} is not a KEYWORD = value pair"),
+            DuplicateKeyword { keyword } => write!(f, "duplicate keyword {keyword}"),
+            InvalidMessage(reason) => write!(f, "invalid OMM: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for OmmParseError {}
+
+/// # Orbit Mean-Elements Message (OMM)
+///
+/// CCSDS 502.0-B orbit mean-elements message holding SGP4 mean elements,
+/// the successor of the TLE format. Unlike TLEs it has no fixed columns, so
+/// it can carry catalog numbers above 99999.
+///
+/// Serializes to the JSON form published by CelesTrak and Space-Track,
+/// which use the KVN keywords as keys. Numeric values may also be given as
+/// strings, as Space-Track does. See [`Omm::from_kvn`] and [`Omm::to_kvn`]
+/// for the KVN form.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "OBJECT_NAME": "ISS (ZARYA)",
+///   "OBJECT_ID": "1998-067A",
+///   "EPOCH": "2025-08-23T18:09:15.082",
+///   "MEAN_MOTION": 15.50129787,
+///   "ECCENTRICITY": 0.0003307,
+///   "INCLINATION": 51.6355,
+///   "RA_OF_ASC_NODE": 332.1708,
+///   "ARG_OF_PERICENTER": 260.2831,
+///   "MEAN_ANOMALY": 99.7785,
+///   "EPHEMERIS_TYPE": 0,
+///   "CLASSIFICATION_TYPE": "U",
+///   "NORAD_CAT_ID": 25544,
+///   "ELEMENT_SET_NO": 999,
+///   "REV_AT_EPOCH": 52564,
+///   "BSTAR": 0.00020339,
+///   "MEAN_MOTION_DOT": 0.00011222,
+///   "MEAN_MOTION_DDOT": 0
+/// }
+/// ```
+///
+/// ## Example
+/// ```
+/// use rustar_types::jobs::{Omm, TleData};
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+///
+/// let omm = tle.to_omm().unwrap();
+/// assert_eq!(omm.object_id, "1998-067A");
+/// assert_eq!(omm.norad_cat_id, Some(25544));
+///
+/// // Both forms round-trip to the same elements
+/// assert_eq!(Omm::from_kvn(&omm.to_kvn()).unwrap().elements(), tle.elements());
+/// let json = serde_json::to_string(&omm).unwrap();
+/// assert_eq!(serde_json::from_str::<Omm>(&json).unwrap().elements(), tle.elements());
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct Omm {
+    /// Format version.
+    #[serde(default = "default_version")]
+    #[schema(example = "3.0")]
+    pub ccsds_omm_vers: String,
+    /// Creation time of the message, as written by its originator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<String>,
+    /// Organization that created the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "18 SPCS")]
+    pub originator: Option<String>,
+
+    /// Satellite name.
+    #[schema(example = "ISS (ZARYA)")]
+    pub object_name: String,
+    /// International (COSPAR) designator, e.g. `"1998-067A"`. Empty if
+    /// unknown.
+    #[serde(default)]
+    #[schema(example = "1998-067A")]
+    pub object_id: String,
+    /// Central body. Always `"EARTH"` for SGP4 elements.
+    #[serde(default = "default_center_name")]
+    pub center_name: String,
+    /// Reference frame. Always `"TEME"` for SGP4 elements.
+    #[serde(default = "default_ref_frame")]
+    pub ref_frame: String,
+    /// Time system of `epoch`. Always `"UTC"` for SGP4 elements.
+    #[serde(default = "default_time_system")]
+    pub time_system: String,
+    /// Theory the mean elements belong to. Only `"SGP4"` elements can be
+    /// propagated.
+    #[serde(default = "default_mean_element_theory")]
+    pub mean_element_theory: String,
+
+    /// Epoch of the elements.
+    #[serde(with = "epoch")]
+    #[schema(value_type = String, example = "2025-08-23T18:09:15.082")]
+    pub epoch: DateTime<Utc>,
+    /// Mean motion, in revolutions per day.
+    #[serde(deserialize_with = "number::deserialize")]
+    pub mean_motion: f64,
+    /// Eccentricity (dimensionless).
+    #[serde(deserialize_with = "number::deserialize")]
+    pub eccentricity: f64,
+    /// Inclination, in degrees.
+    #[serde(deserialize_with = "number::deserialize")]
+    pub inclination: f64,
+    /// Right ascension of the ascending node, in degrees.
+    #[serde(deserialize_with = "number::deserialize")]
+    pub ra_of_asc_node: f64,
+    /// Argument of perigee, in degrees.
+    #[serde(deserialize_with = "number::deserialize")]
+    pub arg_of_pericenter: f64,
+    /// Mean anomaly, in degrees.
+    #[serde(deserialize_with = "number::deserialize")]
+    pub mean_anomaly: f64,
+
+    /// Ephemeris type. Always `0` for publicly distributed elements.
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub ephemeris_type: u8,
+    /// Security classification: `"U"`, `"C"` or `"S"`.
+    #[serde(default = "default_classification_type")]
+    #[schema(example = "U")]
+    pub classification_type: String,
+    /// NORAD catalog number of the satellite, if it has one.
+    #[serde(default, deserialize_with = "number::option")]
+    #[schema(example = 25544)]
+    pub norad_cat_id: Option<u32>,
+    /// Element set number, incremented by the producer for each new set.
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub element_set_no: u16,
+    /// Revolution number at epoch.
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub rev_at_epoch: u32,
+    /// B* drag term, in inverse Earth radii.
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub bstar: f64,
+    /// First time derivative of the mean motion divided by two, in rev/day².
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub mean_motion_dot: f64,
+    /// Second time derivative of the mean motion divided by six, in rev/day³.
+    #[serde(default, deserialize_with = "number::deserialize")]
+    pub mean_motion_ddot: f64,
+}
+
+fn default_version() -> String {
+    "3.0".to_string()
+}
+
+fn default_center_name() -> String {
+    "EARTH".to_string()
+}
+
+fn default_ref_frame() -> String {
+    "TEME".to_string()
+}
+
+fn default_time_system() -> String {
+    "UTC".to_string()
+}
+
+fn default_mean_element_theory() -> String {
+    "SGP4".to_string()
+}
+
+fn default_classification_type() -> String {
+    "U".to_string()
+}
+
+impl Omm {
+    /// The OMM holding `elements`, for the satellite called `name`.
+    pub fn from_elements(name: impl Into<String>, elements: &OrbitalElements) -> Self {
+        let classification_type = match elements.classification {
+            Classification::Unclassified => "U",
+            Classification::Classified => "C",
+            Classification::Secret => "S",
+        };
+        Omm {
+            ccsds_omm_vers: default_version(),
+            creation_date: None,
+            originator: None,
+            object_name: name.into(),
+            object_id: cospar_designator(&elements.international_designator),
+            center_name: default_center_name(),
+            ref_frame: default_ref_frame(),
+            time_system: default_time_system(),
+            mean_element_theory: default_mean_element_theory(),
+            epoch: elements.epoch,
+            mean_motion: elements.mean_motion,
+            eccentricity: elements.eccentricity,
+            inclination: elements.inclination,
+            ra_of_asc_node: elements.right_ascension,
+            arg_of_pericenter: elements.argument_of_perigee,
+            mean_anomaly: elements.mean_anomaly,
+            ephemeris_type: elements.ephemeris_type,
+            classification_type: classification_type.to_string(),
+            norad_cat_id: Some(elements.norad_id),
+            element_set_no: elements.element_set_number,
+            rev_at_epoch: elements.revolution_number,
+            bstar: elements.drag_term,
+            mean_motion_dot: elements.mean_motion_dot,
+            mean_motion_ddot: elements.mean_motion_ddot,
+        }
+    }
+
+    /// Converts this message into typed [`OrbitalElements`].
+    pub fn elements(&self) -> Result<OrbitalElements, ElementsParseError> {
+        OrbitalElements::try_from(self)
+    }
+
+    /// Parses the KVN (`KEYWORD = value`) form of an OMM.
+    ///
+    /// Blank lines, `COMMENT` lines, unknown keywords and trailing units
+    /// such as `[rev/day]` are ignored.
+    pub fn from_kvn(text: &str) -> Result<Self, OmmParseError> {
+        let mut fields = serde_json::Map::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("COMMENT") {
+                continue;
+            }
+            let (keyword, value) = line
+                .split_once('=')
+                .ok_or(OmmParseError::InvalidLine { line: index + 1 })?;
+            let (keyword, value) = (keyword.trim(), value.trim());
+
+            // Only strip brackets from numbers, since names may contain them
+            let value = match value.rsplit_once('[') {
+                Some((number, units))
+                    if units.ends_with(']') && number.trim().parse::<f64>().is_ok() =>
+                {
+                    number.trim()
+                }
+                _ => value,
+            };
+
+            let previous = fields.insert(
+                keyword.to_string(),
+                serde_json::Value::String(value.to_string()),
+            );
+            if previous.is_some() {
+                return Err(OmmParseError::DuplicateKeyword {
+                    keyword: keyword.to_string(),
+                });
+            }
+        }
+
+        serde_json::from_value(serde_json::Value::Object(fields))
+            .map_err(|error| OmmParseError::InvalidMessage(error.to_string()))
+    }
+
+    /// Writes the KVN (`KEYWORD = value`) form of this OMM.
+    pub fn to_kvn(&self) -> String {
+        let mut fields = vec![("CCSDS_OMM_VERS", self.ccsds_omm_vers.clone())];
+        if let Some(creation_date) = &self.creation_date {
+            fields.push(("CREATION_DATE", creation_date.clone()));
+        }
+        if let Some(originator) = &self.originator {
+            fields.push(("ORIGINATOR", originator.clone()));
+        }
+        fields.extend([
+            ("OBJECT_NAME", self.object_name.clone()),
+            ("OBJECT_ID", self.object_id.clone()),
+            ("CENTER_NAME", self.center_name.clone()),
+            ("REF_FRAME", self.ref_frame.clone()),
+            ("TIME_SYSTEM", self.time_system.clone()),
+            ("MEAN_ELEMENT_THEORY", self.mean_element_theory.clone()),
+            ("EPOCH", epoch::format(self.epoch)),
+            ("MEAN_MOTION", self.mean_motion.to_string()),
+            ("ECCENTRICITY", self.eccentricity.to_string()),
+            ("INCLINATION", self.inclination.to_string()),
+            ("RA_OF_ASC_NODE", self.ra_of_asc_node.to_string()),
+            ("ARG_OF_PERICENTER", self.arg_of_pericenter.to_string()),
+            ("MEAN_ANOMALY", self.mean_anomaly.to_string()),
+            ("EPHEMERIS_TYPE", self.ephemeris_type.to_string()),
+            ("CLASSIFICATION_TYPE", self.classification_type.clone()),
+        ]);
+        if let Some(norad_cat_id) = self.norad_cat_id {
+            fields.push(("NORAD_CAT_ID", norad_cat_id.to_string()));
+        }
+        fields.extend([
+            ("ELEMENT_SET_NO", self.element_set_no.to_string()),
+            ("REV_AT_EPOCH", self.rev_at_epoch.to_string()),
+            ("BSTAR", self.bstar.to_string()),
+            ("MEAN_MOTION_DOT", self.mean_motion_dot.to_string()),
+            ("MEAN_MOTION_DDOT", self.mean_motion_ddot.to_string()),
+        ]);
+
+        let mut kvn = String::new();
+        for (keyword, value) in fields {
+            // Writing to a String cannot fail
+            let _ = writeln!(kvn, "{keyword:<19} = {value}");
+        }
+        kvn
+    }
+}
+
+impl TryFrom<&Omm> for OrbitalElements {
+    type Error = ElementsParseError;
+
+    /// Converts the mean elements of an OMM. Fails if they are not SGP4
+    /// elements in the TEME frame with a UTC epoch, or if the satellite has
+    /// no NORAD catalog number.
+    fn try_from(omm: &Omm) -> Result<Self, Self::Error> {
+        use ElementsParseError::*;
+
+        if !omm.mean_element_theory.eq_ignore_ascii_case("SGP4") {
+            return Err(UnsupportedMeanElementTheory);
+        }
+        if !omm.ref_frame.eq_ignore_ascii_case("TEME")
+            || !omm.center_name.eq_ignore_ascii_case("EARTH")
+        {
+            return Err(UnsupportedReferenceFrame);
+        }
+        if !omm.time_system.eq_ignore_ascii_case("UTC") {
+            return Err(UnsupportedTimeSystem);
+        }
+
+        let classification = match omm.classification_type.as_str() {
+            "U" => Classification::Unclassified,
+            "C" => Classification::Classified,
+            "S" => Classification::Secret,
+            _ => return Err(InvalidClassification),
+        };
+
+        Ok(OrbitalElements {
+            norad_id: omm.norad_cat_id.ok_or(InvalidCatalogNumber)?,
+            classification,
+            international_designator: tle_designator(&omm.object_id)
+                .ok_or(InvalidInternationalDesignator)?,
+            epoch: omm.epoch,
+            mean_motion_dot: omm.mean_motion_dot,
+            mean_motion_ddot: omm.mean_motion_ddot,
+            drag_term: omm.bstar,
+            ephemeris_type: omm.ephemeris_type,
+            element_set_number: omm.element_set_no,
+            inclination: omm.inclination,
+            right_ascension: omm.ra_of_asc_node,
+            eccentricity: omm.eccentricity,
+            argument_of_perigee: omm.arg_of_pericenter,
+            mean_anomaly: omm.mean_anomaly,
+            mean_motion: omm.mean_motion,
+            revolution_number: omm.rev_at_epoch,
+        })
+    }
+}
+
+impl TleData {
+    /// Converts this TLE set into an [`Omm`] named after `tle0`.
+    pub fn to_omm(&self) -> Result<Omm, ElementsParseError> {
+        Ok(Omm::from_elements(&self.tle0, &self.elements()?))
+    }
+}
+
+/// Converts a COSPAR designator (`"1998-067A"`) into its TLE form
+/// (`"98067A"`).
+fn tle_designator(object_id: &str) -> Option<String> {
+    if object_id.is_empty() {
+        return Some(String::new());
+    }
+    let (year, piece) = object_id.split_once('-')?;
+    let valid = year.len() == 4
+        && year.chars().all(|c| c.is_ascii_digit())
+        && (4..=6).contains(&piece.len())
+        && piece.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then(|| format!("{}{piece}", &year[2..]))
+}
+
+/// Converts a TLE designator (`"98067A"`) into its COSPAR form
+/// (`"1998-067A"`), using the same two-digit year convention as the TLE
+/// epoch. Malformed designators are kept as they are.
+fn cospar_designator(designator: &str) -> String {
+    match designator
+        .get(..2)
+        .and_then(|year| year.parse::<u32>().ok())
+    {
+        Some(year) => {
+            let century = if year < 57 { 2000 } else { 1900 };
+            format!("{}-{}", century + year, &designator[2..])
+        }
+        None => designator.to_string(),
+    }
+}
+
+/// Serde adapter for OMM epochs, which are written without a time zone
+/// suffix. Calendar (`2025-08-23T18:09:15.082`) and ordinal
+/// (`2025-235T18:09:15.082`) dates are accepted, with or without a trailing
+/// `Z`.
+mod epoch {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    pub fn format(epoch: DateTime<Utc>) -> String {
+        epoch.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+
+    pub fn serialize<S: Serializer>(
+        epoch: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format(*epoch))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let naive = text.trim().trim_end_matches('Z');
+        ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%jT%H:%M:%S%.f"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(naive, format).ok())
+            .map(|epoch| epoch.and_utc())
+            .ok_or_else(|| de::Error::custom(format!("invalid epoch {text:?}")))
+    }
+}
+
+/// Serde adapter for numbers that may also be given as strings.
+mod number {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, de};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Integer(i64),
+        Float(f64),
+        Text(String),
+    }
+
+    impl Raw {
+        fn parse<T: FromStr, E: de::Error>(self) -> Result<T, E>
+        where
+            T::Err: Display,
+        {
+            let text = match self {
+                Raw::Integer(number) => number.to_string(),
+                Raw::Float(number) => number.to_string(),
+                Raw::Text(text) => text,
+            };
+            text.trim().parse().map_err(E::custom)
+        }
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        T::Err: Display,
+    {
+        Raw::deserialize(deserializer)?.parse()
+    }
+
+    pub fn option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        T::Err: Display,
+    {
+        Option::<Raw>::deserialize(deserializer)?
+            .map(Raw::parse)
+            .transpose()
+    }
+}
@@ -0,0 +1,432 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::mqtt::topics::GroundStationId;
+
+/// # Job Status
+///
+/// Lifecycle state of a [`Job`](super::Job) at a ground station.
+///
+/// Legal transitions:
+///
+/// ```text
+/// Received ──► Scheduled ──► Started ──► Completed
+///    │             │            │
+///    ├─► Skipped ◄─┤            ├─► Aborted
+///    ├─► Cancelled◄┤            │
+///    └─► Error ◄───┴────────────┘
+/// ```
+///
+/// `Completed`, `Error`, `Cancelled`, `Aborted` and `Skipped` are terminal.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// The station accepted the job.
+    Received,
+    /// The job is queued for its tracking window.
+    Scheduled,
+    /// Tracking is in progress.
+    Started,
+    /// Tracking finished normally.
+    Completed,
+    /// The job failed.
+    Error,
+    /// The job was withdrawn before tracking started.
+    Cancelled,
+    /// Tracking was interrupted after it started.
+    Aborted,
+    /// The station decided not to run the job (e.g. a higher priority pass).
+    Skipped,
+}
+
+/// # Job Failure
+///
+/// Structured details of why a job ended in `Error`, `Aborted` or `Skipped`.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "reason": "HardwareFault",
+///   "detail": "Rotator did not reach commanded azimuth"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct JobFailure {
+    /// Category of the failure.
+    pub reason: FailureReason,
+    /// Free-form description for operators.
+    #[schema(example = "Rotator did not reach commanded azimuth")]
+    pub detail: Option<String>,
+}
+
+/// Category of a [`JobFailure`].
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The job itself is malformed or infeasible.
+    InvalidJob,
+    /// The orbital data could not be propagated or is too old.
+    InvalidOrbitalData,
+    /// Another job holds the station during the window.
+    Conflict,
+    /// The tracking window passed before the job could run.
+    Expired,
+    /// Antenna, rotator or radio failure.
+    HardwareFault,
+    /// Requested by an operator.
+    OperatorRequest,
+    /// Any other cause; see `detail`.
+    Other,
+}
+
+/// # Job Status Update
+///
+/// A status change reported by a ground station. Updates carry enough
+/// context to rebuild a job's whole [`JobHistory`] from them alone.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "job_id": 12345,
+///   "ground_station_id": "gs-buenos-aires",
+///   "timestamp": "2025-09-19T12:00:00Z",
+///   "status": "Started",
+///   "message": null,
+///   "failure": null
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct JobStatusUpdate {
+    /// Identifier of the job this update refers to.
+    #[schema(example = 12345)]
+    pub job_id: u64,
+    /// Identifier of the reporting ground station.
+    pub ground_station_id: GroundStationId,
+    /// UTC timestamp of the status change.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub timestamp: DateTime<Utc>,
+    /// New status of the job.
+    pub status: JobStatus,
+    /// Optional human-readable message.
+    pub message: Option<String>,
+    /// Failure details, required when `status` is `Error`, `Aborted` or
+    /// `Skipped`.
+    pub failure: Option<JobFailure>,
+}
+
+/// Error type for illegal job status changes
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobTransitionError {
+    /// The status machine does not allow moving from `from` to `to`
+    IllegalTransition { from: JobStatus, to: JobStatus },
+    /// The first update of a history is not `Received`
+    NotReceived { status: JobStatus },
+    /// The update belongs to a different job
+    JobMismatch { expected: u64, found: u64 },
+    /// The update comes from a different ground station
+    GroundStationMismatch {
+        expected: GroundStationId,
+        found: GroundStationId,
+    },
+    /// No updates were given to rebuild a history from
+    EmptyHistory,
+    /// An `Error`, `Aborted` or `Skipped` update carries no failure details
+    MissingFailure { status: JobStatus },
+}
+
+impl fmt::Display for JobTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobTransitionError::IllegalTransition { from, to } => {
+                write!(f, "illegal transition from {from:?} to {to:?}")
+            }
+            JobTransitionError::NotReceived { status } => {
+                write!(f, "history starts with {status:?} instead of Received")
+            }
+            JobTransitionError::JobMismatch { expected, found } => {
+                write!(f, "update for job {found} applied to job {expected}")
+            }
+            JobTransitionError::GroundStationMismatch { expected, found } => write!(
+                f,
+                "update from ground station {found:?} applied to ground station {expected:?}"
+            ),
+            JobTransitionError::EmptyHistory => write!(f, "history has no updates"),
+            JobTransitionError::MissingFailure { status } => {
+                write!(f, "{status:?} update has no failure details")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JobTransitionError {}
+
+impl JobStatus {
+    /// Whether no further transitions are allowed from this status.
+    pub fn is_terminal(self) -> bool {
+        !matches!(
+            self,
+            JobStatus::Received | JobStatus::Scheduled | JobStatus::Started
+        )
+    }
+
+    /// Whether updates to this status must carry a [`JobFailure`].
+    pub fn requires_failure(self) -> bool {
+        matches!(
+            self,
+            JobStatus::Error | JobStatus::Aborted | JobStatus::Skipped
+        )
+    }
+
+    /// Whether moving from `self` to `next` is a legal transition.
+    pub fn can_transition_to(self, next: JobStatus) -> bool {
+        use JobStatus::*;
+
+        matches!(
+            (self, next),
+            (Received, Scheduled | Skipped | Cancelled | Error)
+                | (Scheduled, Started | Skipped | Cancelled | Error)
+                | (Started, Completed | Aborted | Error)
+        )
+    }
+
+    /// Returns `next` if moving to it from `self` is legal.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::jobs::{JobStatus, JobTransitionError};
+    ///
+    /// assert_eq!(JobStatus::Scheduled.transition(JobStatus::Started), Ok(JobStatus::Started));
+    /// assert_eq!(
+    ///     JobStatus::Completed.transition(JobStatus::Started),
+    ///     Err(JobTransitionError::IllegalTransition {
+    ///         from: JobStatus::Completed,
+    ///         to: JobStatus::Started,
+    ///     })
+    /// );
+    /// ```
+    pub fn transition(self, next: JobStatus) -> Result<JobStatus, JobTransitionError> {
+        if self.can_transition_to(next) {
+            Ok(next)
+        } else {
+            Err(JobTransitionError::IllegalTransition {
+                from: self,
+                to: next,
+            })
+        }
+    }
+}
+
+impl JobFailure {
+    pub fn new(reason: FailureReason, detail: Option<String>) -> Self {
+        JobFailure { reason, detail }
+    }
+}
+
+impl JobStatusUpdate {
+    pub fn new(
+        job_id: u64,
+        ground_station_id: GroundStationId,
+        timestamp: DateTime<Utc>,
+        status: JobStatus,
+    ) -> Self {
+        JobStatusUpdate {
+            job_id,
+            ground_station_id,
+            timestamp,
+            status,
+            message: None,
+            failure: None,
+        }
+    }
+
+    /// Attaches a human-readable message to the update.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Attaches failure details to the update.
+    pub fn with_failure(mut self, failure: JobFailure) -> Self {
+        self.failure = Some(failure);
+        self
+    }
+}
+
+/// # Job History
+///
+/// The validated sequence of status updates of a single job at a single
+/// ground station.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::jobs::{JobHistory, JobStatus, JobStatusUpdate};
+/// use rustar_types::mqtt::topics::GroundStationId;
+///
+/// let gs_1 = GroundStationId::new("gs-1").unwrap();
+/// let t0 = Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap();
+/// let update = |minutes, status| {
+///     JobStatusUpdate::new(12345, gs_1.clone(), t0 + Duration::minutes(minutes), status)
+/// };
+/// let updates = vec![
+///     update(0, JobStatus::Received),
+///     update(20, JobStatus::Started),
+///     update(1, JobStatus::Scheduled),
+/// ];
+///
+/// // Updates may arrive out of order
+/// let history = JobHistory::from_updates(updates).unwrap();
+/// assert_eq!(history.status(), JobStatus::Started);
+///
+/// // Deserializing replays the updates, so an invalid history is rejected
+/// let json = serde_json::to_value(&history).unwrap();
+/// assert_eq!(serde_json::from_value::<JobHistory>(json.clone()).unwrap(), history);
+///
+/// let mut skipped = json;
+/// skipped["updates"][1]["status"] = "Skipped".into();
+/// assert!(serde_json::from_value::<JobHistory>(skipped).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "HistoryFields")]
+pub struct JobHistory {
+    job_id: u64,
+    ground_station_id: GroundStationId,
+    updates: Vec<JobStatusUpdate>,
+}
+
+impl JobHistory {
+    /// Starts a history from its initial `Received` update.
+    pub fn new(update: JobStatusUpdate) -> Result<Self, JobTransitionError> {
+        if update.status != JobStatus::Received {
+            return Err(JobTransitionError::NotReceived {
+                status: update.status,
+            });
+        }
+
+        Ok(JobHistory {
+            job_id: update.job_id,
+            ground_station_id: update.ground_station_id.clone(),
+            updates: vec![update],
+        })
+    }
+
+    /// Rebuilds a history from its updates, ordering them by timestamp.
+    pub fn from_updates(
+        updates: impl IntoIterator<Item = JobStatusUpdate>,
+    ) -> Result<Self, JobTransitionError> {
+        let mut updates: Vec<_> = updates.into_iter().collect();
+        updates.sort_by_key(|update| update.timestamp);
+
+        let mut updates = updates.into_iter();
+        let mut history = JobHistory::new(updates.next().ok_or(JobTransitionError::EmptyHistory)?)?;
+        for update in updates {
+            history.apply(update)?;
+        }
+        Ok(history)
+    }
+
+    /// Appends an update, checking that it belongs to this job and station,
+    /// that the status change is legal and that failures carry their
+    /// details.
+    ///
+    /// ## Example
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use rustar_types::jobs::{
+    ///     FailureReason, JobFailure, JobHistory, JobStatus, JobStatusUpdate, JobTransitionError,
+    /// };
+    /// use rustar_types::mqtt::topics::GroundStationId;
+    ///
+    /// let gs_1 = GroundStationId::new("gs-1").unwrap();
+    /// let t0 = Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap();
+    /// let received = JobStatusUpdate::new(12345, gs_1.clone(), t0, JobStatus::Received);
+    /// let mut history = JobHistory::new(received).unwrap();
+    ///
+    /// let skipped = JobStatusUpdate::new(12345, gs_1, t0, JobStatus::Skipped);
+    /// assert_eq!(
+    ///     history.apply(skipped.clone()),
+    ///     Err(JobTransitionError::MissingFailure { status: JobStatus::Skipped })
+    /// );
+    ///
+    /// let failure = JobFailure::new(FailureReason::Conflict, None);
+    /// assert_eq!(history.apply(skipped.with_failure(failure)), Ok(()));
+    /// ```
+    pub fn apply(&mut self, update: JobStatusUpdate) -> Result<(), JobTransitionError> {
+        if update.job_id != self.job_id {
+            return Err(JobTransitionError::JobMismatch {
+                expected: self.job_id,
+                found: update.job_id,
+            });
+        }
+        if update.ground_station_id != self.ground_station_id {
+            return Err(JobTransitionError::GroundStationMismatch {
+                expected: self.ground_station_id.clone(),
+                found: update.ground_station_id,
+            });
+        }
+
+        self.status().transition(update.status)?;
+        if update.status.requires_failure() && update.failure.is_none() {
+            return Err(JobTransitionError::MissingFailure {
+                status: update.status,
+            });
+        }
+        self.updates.push(update);
+        Ok(())
+    }
+
+    pub fn job_id(&self) -> u64 {
+        self.job_id
+    }
+
+    pub fn ground_station_id(&self) -> &GroundStationId {
+        &self.ground_station_id
+    }
+
+    /// Current status of the job.
+    pub fn status(&self) -> JobStatus {
+        // A history always holds at least its `Received` update
+        self.updates
+            .last()
+            .map_or(JobStatus::Received, |update| update.status)
+    }
+
+    /// All updates, oldest first.
+    pub fn updates(&self) -> &[JobStatusUpdate] {
+        &self.updates
+    }
+}
+
+/// Serialized form of a [`JobHistory`], validated by replaying its updates.
+#[derive(Deserialize)]
+struct HistoryFields {
+    job_id: u64,
+    ground_station_id: GroundStationId,
+    updates: Vec<JobStatusUpdate>,
+}
+
+impl TryFrom<HistoryFields> for JobHistory {
+    type Error = JobTransitionError;
+
+    fn try_from(fields: HistoryFields) -> Result<Self, Self::Error> {
+        let mut updates = fields.updates.into_iter();
+        let mut history = JobHistory::new(updates.next().ok_or(JobTransitionError::EmptyHistory)?)?;
+        if history.job_id != fields.job_id {
+            return Err(JobTransitionError::JobMismatch {
+                expected: fields.job_id,
+                found: history.job_id,
+            });
+        }
+        if history.ground_station_id != fields.ground_station_id {
+            return Err(JobTransitionError::GroundStationMismatch {
+                expected: fields.ground_station_id,
+                found: history.ground_station_id,
+            });
+        }
+        for update in updates {
+            history.apply(update)?;
+        }
+        Ok(history)
+    }
+}
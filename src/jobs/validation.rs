@@ -0,0 +1,434 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{Job, OrbitRegime};
+use crate::mqtt::topics::GroundStationId;
+use crate::sgp4::Sgp4Error;
+use crate::stations::GroundStation;
+use crate::tracking::{
+    CelestialBody, TransitInterval, celestial_transits, pointing_track, sample_times,
+};
+
+/// # Job Violation
+///
+/// A reason why a ground station cannot execute a job.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub enum JobViolation {
+    /// No band of the station can receive `rx_frequency`.
+    RxFrequencyUnsupported { frequency: f64 },
+    /// The job has uplink data, but no band of the station can transmit on
+    /// `tx_frequency`.
+    TxFrequencyUnsupported { frequency: f64 },
+    /// The job has uplink data, but the station has no transmitter.
+    NoTransmitter,
+    /// The job has uplink data, but the station is not licensed to transmit.
+    TransmitterUnlicensed,
+    /// The window does not start before it ends.
+    InvalidWindow,
+    /// The window has already ended.
+    WindowInPast {
+        #[schema(value_type = String, format = "date-time")]
+        end: DateTime<Utc>,
+    },
+    /// The window is longer than the validator allows.
+    WindowTooLong { seconds: i64, max_seconds: i64 },
+    /// The satellite never rises above the station's elevation mask during
+    /// the window. `max_elevation` is the highest elevation reached, in
+    /// degrees.
+    BelowElevationMask { max_elevation: f64 },
+    /// The satellite could not be propagated over the window. `reason`
+    /// describes the [`Sgp4Error`].
+    PropagationFailed { reason: String },
+    /// The TLE epoch is further from the window start than the staleness
+    /// policy allows for its orbit regime.
+    StaleTle {
+        regime: OrbitRegime,
+        age_seconds: i64,
+        max_age_seconds: i64,
+    },
+}
+
+/// # Job Warning
+///
+/// A condition that does not prevent a station from executing a job, but
+/// may degrade it.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::{Job, JobValidator, JobWarning};
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::stations::{FrequencyBand, GroundStation};
+/// use rustar_types::tracking::CelestialBody;
+///
+/// // The ISS sets towards the rising Sun
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 8, 24, 5, 16, 6).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 24, 5, 26, 58).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+///
+/// let id = GroundStationId::new("gs-paris").unwrap();
+/// let mut station = GroundStation::new(id, Geodetic::new(48.8566, 2.3522, 0.035));
+/// station.bands.push(FrequencyBand::receive_only(144_000_000.0, 146_000_000.0));
+///
+/// let now = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let report = JobValidator::default().validate(&job, &station, now);
+///
+/// assert!(report.is_feasible());
+/// let [JobWarning::CelestialTransit(transit)] = report.warnings.as_slice() else {
+///     panic!("expected a Sun transit, got {:?}", report.warnings);
+/// };
+/// assert_eq!(transit.body, CelestialBody::Sun);
+/// assert!(transit.min_separation < 1.0);
+/// assert_eq!(transit.end, job.end);
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub enum JobWarning {
+    /// The TLE epoch is further from the window start than the staleness
+    /// policy's warning threshold for its orbit regime.
+    AgingTle {
+        regime: OrbitRegime,
+        age_seconds: i64,
+        warn_age_seconds: i64,
+    },
+    /// The antenna points close to the Sun or the Moon, whose noise may
+    /// drown the signal.
+    CelestialTransit(TransitInterval),
+}
+
+/// # Validation Report
+///
+/// Outcome of checking a job against a ground station.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ValidationReport {
+    #[schema(example = 12345)]
+    pub job_id: u64,
+    pub ground_station_id: GroundStationId,
+    /// Every violation found; empty if the job is feasible.
+    pub violations: Vec<JobViolation>,
+    /// Conditions that may degrade the job without preventing it.
+    #[serde(default)]
+    pub warnings: Vec<JobWarning>,
+}
+
+impl ValidationReport {
+    /// Whether the station can execute the job.
+    pub fn is_feasible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// # Job Validator
+///
+/// Checks whether a ground station can execute a job before it is
+/// dispatched.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::{Job, JobValidator, JobViolation};
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::stations::{ElevationMask, FrequencyBand, GroundStation};
+///
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 36, 18).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 47, 14).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .uplink(b"Hello".to_vec())
+///     .build()
+///     .unwrap();
+///
+/// let id = GroundStationId::new("gs-buenos-aires").unwrap();
+/// let mut station = GroundStation::new(id, Geodetic::new(-34.6037, -58.3816, 0.025));
+/// station.bands.push(FrequencyBand::receive_only(144_000_000.0, 146_000_000.0));
+/// station.elevation_mask = ElevationMask::constant(10.0);
+///
+/// let now = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let report = JobValidator::default().validate(&job, &station, now);
+///
+/// assert!(!report.is_feasible());
+/// assert_eq!(
+///     report.violations,
+///     vec![
+///         JobViolation::TxFrequencyUnsupported { frequency: 437_500_000.0 },
+///         JobViolation::NoTransmitter,
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct JobValidator {
+    /// Longest window a job may request.
+    pub max_window: Duration,
+    /// Time between elevation samples when checking visibility.
+    pub visibility_step: Duration,
+    /// Maximum TLE ages.
+    pub staleness: StalenessPolicy,
+    /// Closest the antenna may point to the Sun, in degrees, without a
+    /// [`JobWarning::CelestialTransit`]. `None` disables the check.
+    pub sun_separation: Option<f64>,
+    /// Same as `sun_separation`, for the Moon.
+    pub moon_separation: Option<f64>,
+}
+
+impl Default for JobValidator {
+    /// Windows of up to 30 minutes, visibility sampled every 10 seconds, the
+    /// default [`StalenessPolicy`], and warnings when pointing within 5° of
+    /// the Sun. The Moon is only bright enough to matter for large dishes,
+    /// so it is not checked.
+    fn default() -> Self {
+        JobValidator {
+            max_window: Duration::minutes(30),
+            visibility_step: Duration::seconds(10),
+            staleness: StalenessPolicy::default(),
+            sun_separation: Some(5.0),
+            moon_separation: None,
+        }
+    }
+}
+
+/// Warning and rejection ages of a TLE.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StalenessThresholds {
+    /// Older TLEs produce a [`JobWarning::AgingTle`].
+    pub warn: Duration,
+    /// Older TLEs produce a [`JobViolation::StaleTle`].
+    pub reject: Duration,
+}
+
+/// # Staleness Policy
+///
+/// How old a job's TLE may be at the start of its window, by orbit regime.
+/// The age is measured in either direction, since propagating far back
+/// from the epoch is as inaccurate as propagating far forward.
+///
+/// ## Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::{Job, JobValidator, JobViolation, OrbitRegime};
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::stations::{FrequencyBand, GroundStation};
+///
+/// // A TLE from August for a job in late September
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 9, 19, 12, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 9, 19, 12, 15, 0).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+///
+/// let id = GroundStationId::new("gs-1").unwrap();
+/// let mut station = GroundStation::new(id, Geodetic::new(-34.6037, -58.3816, 0.025));
+/// station.bands.push(FrequencyBand::receive_only(144_000_000.0, 146_000_000.0));
+///
+/// let now = Utc.with_ymd_and_hms(2025, 9, 19, 0, 0, 0).unwrap();
+/// let report = JobValidator::default().validate(&job, &station, now);
+/// assert!(report.violations.iter().any(|violation| matches!(
+///     violation,
+///     JobViolation::StaleTle { regime: OrbitRegime::LowEarth, .. }
+/// )));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StalenessPolicy {
+    pub low_earth: StalenessThresholds,
+    pub medium_earth: StalenessThresholds,
+    pub geosynchronous: StalenessThresholds,
+    pub highly_elliptical: StalenessThresholds,
+}
+
+impl StalenessPolicy {
+    pub fn thresholds(&self, regime: OrbitRegime) -> StalenessThresholds {
+        match regime {
+            OrbitRegime::LowEarth => self.low_earth,
+            OrbitRegime::MediumEarth => self.medium_earth,
+            OrbitRegime::Geosynchronous => self.geosynchronous,
+            OrbitRegime::HighlyElliptical => self.highly_elliptical,
+        }
+    }
+}
+
+impl Default for StalenessPolicy {
+    /// Warn after 3 days and reject after 7 for low Earth and highly
+    /// elliptical orbits, 7 and 14 days for medium Earth orbits, and 14 and
+    /// 30 days for geosynchronous orbits.
+    fn default() -> Self {
+        let thresholds = |warn, reject| StalenessThresholds {
+            warn: Duration::days(warn),
+            reject: Duration::days(reject),
+        };
+        StalenessPolicy {
+            low_earth: thresholds(3, 7),
+            medium_earth: thresholds(7, 14),
+            geosynchronous: thresholds(14, 30),
+            highly_elliptical: thresholds(3, 7),
+        }
+    }
+}
+
+impl JobValidator {
+    /// Checks `job` against `station` at time `now`, reporting every
+    /// violation found.
+    ///
+    /// `tx_frequency` is only checked when the job has uplink data.
+    pub fn validate(
+        &self,
+        job: &Job,
+        station: &GroundStation,
+        now: DateTime<Utc>,
+    ) -> ValidationReport {
+        let mut violations = Vec::new();
+        let mut warnings = Vec::new();
+
+        if !station.can_receive(job.rx_frequency) {
+            violations.push(JobViolation::RxFrequencyUnsupported {
+                frequency: job.rx_frequency,
+            });
+        }
+
+        if job.uplink.is_some() {
+            if !station.has_transmit_band(job.tx_frequency) {
+                violations.push(JobViolation::TxFrequencyUnsupported {
+                    frequency: job.tx_frequency,
+                });
+            }
+            match &station.transmitter {
+                None => violations.push(JobViolation::NoTransmitter),
+                Some(transmitter) if !transmitter.licensed => {
+                    violations.push(JobViolation::TransmitterUnlicensed)
+                }
+                Some(_) => {}
+            }
+        }
+
+        if job.start >= job.end {
+            violations.push(JobViolation::InvalidWindow);
+        }
+        if job.end <= now {
+            violations.push(JobViolation::WindowInPast { end: job.end });
+        }
+        let window = job.end - job.start;
+        if window > self.max_window {
+            violations.push(JobViolation::WindowTooLong {
+                seconds: window.num_seconds(),
+                max_seconds: self.max_window.num_seconds(),
+            });
+        }
+
+        match self.max_elevation_above_mask(job, station) {
+            Ok((_, true)) => {}
+            Ok((max_elevation, false)) => {
+                violations.push(JobViolation::BelowElevationMask { max_elevation })
+            }
+            Err(error) => violations.push(JobViolation::PropagationFailed {
+                reason: error.to_string(),
+            }),
+        }
+
+        // Malformed elements are reported as a propagation failure
+        if let Ok(elements) = job.orbit.elements() {
+            let regime = elements.regime();
+            let thresholds = self.staleness.thresholds(regime);
+            let age = (job.start - elements.epoch).abs();
+            if age > thresholds.reject {
+                violations.push(JobViolation::StaleTle {
+                    regime,
+                    age_seconds: age.num_seconds(),
+                    max_age_seconds: thresholds.reject.num_seconds(),
+                });
+            } else if age > thresholds.warn {
+                warnings.push(JobWarning::AgingTle {
+                    regime,
+                    age_seconds: age.num_seconds(),
+                    warn_age_seconds: thresholds.warn.num_seconds(),
+                });
+            }
+        }
+
+        warnings.extend(
+            self.celestial_transits(job, station)
+                .into_iter()
+                .map(JobWarning::CelestialTransit),
+        );
+
+        ValidationReport {
+            job_id: job.id,
+            ground_station_id: station.id.clone(),
+            violations,
+            warnings,
+        }
+    }
+
+    /// Sun and Moon transits of the pointing track, sampled like
+    /// visibility. Propagation failures and empty windows are reported
+    /// elsewhere and yield no transits.
+    fn celestial_transits(&self, job: &Job, station: &GroundStation) -> Vec<TransitInterval> {
+        let bodies = [
+            (CelestialBody::Sun, self.sun_separation),
+            (CelestialBody::Moon, self.moon_separation),
+        ];
+        if bodies.iter().all(|(_, separation)| separation.is_none()) {
+            return Vec::new();
+        }
+        let Ok(track) = pointing_track(
+            job,
+            &station.location,
+            station.rotator.mode,
+            self.visibility_step,
+        ) else {
+            return Vec::new();
+        };
+
+        bodies
+            .into_iter()
+            .filter_map(|(body, separation)| Some((body, separation?)))
+            .flat_map(|(body, separation)| {
+                celestial_transits(&track, &station.location, body, separation)
+            })
+            .collect()
+    }
+
+    /// Highest elevation reached during the window, and whether the
+    /// satellite is ever above the mask.
+    fn max_elevation_above_mask(
+        &self,
+        job: &Job,
+        station: &GroundStation,
+    ) -> Result<(f64, bool), Sgp4Error> {
+        let propagator = job.orbit.propagator()?;
+        // An empty window is reported on its own; just check its start
+        let times = sample_times(job.start, job.end, self.visibility_step)
+            .unwrap_or_else(|_| vec![job.start]);
+
+        let mut max_elevation = f64::NEG_INFINITY;
+        let mut visible = false;
+        for time in times {
+            let look = propagator.observe(&station.location, time)?;
+            max_elevation = max_elevation.max(look.elevation);
+            visible |= station.is_visible(&look);
+        }
+        Ok((max_elevation, visible))
+    }
+}
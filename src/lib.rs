@@ -1,3 +1,12 @@
+pub mod codec;
+pub mod ephemeris;
+pub mod export;
+pub mod frames;
 pub mod jobs;
 pub mod mqtt;
+pub mod passes;
+pub mod scheduler;
+pub mod sgp4;
+pub mod stations;
 pub mod telemetry;
+pub mod tracking;
@@ -0,0 +1,231 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::telemetry::TelemetryMessage;
+use super::topics::{GroundStationId, Topic};
+use crate::jobs::{Job, JobStatusUpdate};
+
+/// Error type for envelopes that don't match their topic or schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvelopeError {
+    /// The envelope carries a different message type than expected
+    MessageTypeMismatch {
+        expected: MessageType,
+        found: MessageType,
+    },
+    /// The envelope uses a newer schema than this crate understands
+    UnsupportedSchemaVersion { supported: u32, found: u32 },
+    /// The payload is from a different ground station than the topic's
+    GroundStationMismatch {
+        expected: GroundStationId,
+        found: GroundStationId,
+    },
+    /// The payload is about a different job than the topic's
+    JobMismatch { expected: u64, found: u64 },
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use EnvelopeError::*;
+        match self {
+            MessageTypeMismatch { expected, found } => {
+                write!(f, "expected a {expected:?} message, found {found:?}")
+            }
+            UnsupportedSchemaVersion { supported, found } => write!(
+                f,
+                "schema version {found} is newer than the supported version {supported}"
+            ),
+            GroundStationMismatch { expected, found } => write!(
+                f,
+                "payload is from ground station {found}, topic is for {expected}"
+            ),
+            JobMismatch { expected, found } => {
+                write!(f, "payload is for job {found}, topic is for job {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {}
+
+/// Type of the payload of an [`Envelope`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageType {
+    Job,
+    JobStatusUpdate,
+    Telemetry,
+}
+
+/// A payload that can travel on the ground station bus.
+pub trait Message {
+    const MESSAGE_TYPE: MessageType;
+    /// Version of the payload schema, increased on incompatible changes.
+    const SCHEMA_VERSION: u32;
+
+    /// The ground station the payload is from, if it names one.
+    fn ground_station_id(&self) -> Option<&GroundStationId> {
+        None
+    }
+
+    /// The job the payload is about, if it names one.
+    fn job_id(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl Message for Job {
+    const MESSAGE_TYPE: MessageType = MessageType::Job;
+    const SCHEMA_VERSION: u32 = 1;
+
+    fn job_id(&self) -> Option<u64> {
+        Some(self.id)
+    }
+}
+
+impl Message for JobStatusUpdate {
+    const MESSAGE_TYPE: MessageType = MessageType::JobStatusUpdate;
+    const SCHEMA_VERSION: u32 = 1;
+
+    fn ground_station_id(&self) -> Option<&GroundStationId> {
+        Some(&self.ground_station_id)
+    }
+
+    fn job_id(&self) -> Option<u64> {
+        Some(self.job_id)
+    }
+}
+
+impl Message for TelemetryMessage {
+    const MESSAGE_TYPE: MessageType = MessageType::Telemetry;
+    const SCHEMA_VERSION: u32 = 1;
+
+    fn ground_station_id(&self) -> Option<&GroundStationId> {
+        Some(&self.ground_station_id)
+    }
+}
+
+/// # Envelope
+///
+/// Wrapper of every message published on the ground station bus.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "message_type": "JobStatusUpdate",
+///   "schema_version": 1,
+///   "message_id": "2b1f0c6e-4c1f-4e5b-9d6a-1f0e6f3c9a10",
+///   "correlation_id": "8d7c6b5a-4e3f-4a2b-8c1d-0e9f8a7b6c5d",
+///   "timestamp": "2025-09-19T12:00:00Z",
+///   "payload": { ... }
+/// }
+/// ```
+///
+/// ## Example
+/// ```
+/// use chrono::Utc;
+/// use rustar_types::jobs::{JobStatus, JobStatusUpdate};
+/// use rustar_types::mqtt::envelope::{Envelope, EnvelopeError};
+/// use rustar_types::mqtt::topics::{GroundStationId, Topic};
+///
+/// let gs_1 = GroundStationId::new("gs-1").unwrap();
+/// let update = JobStatusUpdate::new(12345, gs_1, Utc::now(), JobStatus::Started);
+/// let envelope = Envelope::new(update).with_correlation_id("job-12345");
+///
+/// assert!(envelope.validate(&Topic::job_status("gs-1", 12345).unwrap()).is_ok());
+/// assert!(envelope.validate(&Topic::telemetry("gs-1").unwrap()).is_err());
+///
+/// // The payload must be about the topic's station and job
+/// assert!(matches!(
+///     envelope.validate(&Topic::job_status("gs-2", 12345).unwrap()),
+///     Err(EnvelopeError::GroundStationMismatch { .. })
+/// ));
+/// assert_eq!(
+///     envelope.validate(&Topic::job_status("gs-1", 54321).unwrap()),
+///     Err(EnvelopeError::JobMismatch { expected: 54321, found: 12345 })
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Envelope<T> {
+    /// Type of `payload`.
+    pub message_type: MessageType,
+    /// Schema version of `payload`.
+    pub schema_version: u32,
+    /// Unique identifier of this message.
+    pub message_id: String,
+    /// Identifier shared by related messages, such as a request and its
+    /// replies.
+    pub correlation_id: Option<String>,
+    /// UTC timestamp of publication.
+    pub timestamp: DateTime<Utc>,
+    pub payload: T,
+}
+
+impl<T: Message> Envelope<T> {
+    /// Wraps a payload with a fresh message id, timestamped now.
+    pub fn new(payload: T) -> Self {
+        Envelope {
+            message_type: T::MESSAGE_TYPE,
+            schema_version: T::SCHEMA_VERSION,
+            message_id: Uuid::new_v4().to_string(),
+            correlation_id: None,
+            timestamp: Utc::now(),
+            payload,
+        }
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(correlation_id.into());
+        self
+    }
+
+    /// Wraps a reply to this message, correlated with it.
+    ///
+    /// The reply keeps this message's correlation id, or uses its message
+    /// id if it has none.
+    pub fn reply<U: Message>(&self, payload: U) -> Envelope<U> {
+        let correlation_id = self
+            .correlation_id
+            .clone()
+            .unwrap_or_else(|| self.message_id.clone());
+        Envelope::new(payload).with_correlation_id(correlation_id)
+    }
+
+    /// Checks that the envelope may be published on, or was received from,
+    /// `topic`, and that its schema is one this crate understands.
+    ///
+    /// The ground station and job named by the payload, if any, must be
+    /// the topic's. Jobs name no station, and only status topics name a
+    /// job.
+    pub fn validate(&self, topic: &Topic) -> Result<(), EnvelopeError> {
+        let expected = topic.message_type();
+        if self.message_type != expected || T::MESSAGE_TYPE != expected {
+            return Err(EnvelopeError::MessageTypeMismatch {
+                expected,
+                found: self.message_type,
+            });
+        }
+        if self.schema_version > T::SCHEMA_VERSION {
+            return Err(EnvelopeError::UnsupportedSchemaVersion {
+                supported: T::SCHEMA_VERSION,
+                found: self.schema_version,
+            });
+        }
+        if let Some(found) = self.payload.ground_station_id()
+            && found != topic.ground_station_id()
+        {
+            return Err(EnvelopeError::GroundStationMismatch {
+                expected: topic.ground_station_id().clone(),
+                found: found.clone(),
+            });
+        }
+        if let (Some(expected), Some(found)) = (topic.job_id(), self.payload.job_id())
+            && found != expected
+        {
+            return Err(EnvelopeError::JobMismatch { expected, found });
+        }
+        Ok(())
+    }
+}
@@ -1 +1,4 @@
+pub mod envelope;
 pub mod telemetry;
+pub mod topics;
+pub mod wire;
@@ -1,21 +1,24 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::topics::GroundStationId;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TelemetryMessage {
-    pub ground_station_id: String,
+    pub ground_station_id: GroundStationId,
     pub timestamp: DateTime<Utc>,
+    #[serde(with = "super::wire::bytes")]
     pub payload: Vec<u8>,
 }
 
 impl TelemetryMessage {
     pub fn new(
-        ground_station_id: impl Into<String>,
+        ground_station_id: GroundStationId,
         timestamp: DateTime<Utc>,
         payload: Vec<u8>,
     ) -> Self {
         Self {
-            ground_station_id: ground_station_id.into(),
+            ground_station_id,
             timestamp,
             payload,
         }
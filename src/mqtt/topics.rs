@@ -0,0 +1,279 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::envelope::MessageType;
+
+/// Root level of every ground station topic.
+const ROOT: &str = "gs";
+
+/// Error type for topic parsing failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicParseError {
+    /// The topic does not start with `gs/`
+    InvalidRoot,
+    /// The ground station id is empty or contains `/`, `+` or `#`
+    InvalidGroundStationId,
+    /// The job id is not an unsigned 64-bit integer
+    InvalidJobId,
+    /// The levels after the ground station id match no known topic
+    UnknownTopic,
+}
+
+impl fmt::Display for TopicParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TopicParseError::*;
+        match self {
+            InvalidRoot => f.write_str("topic does not start with gs/"),
+            InvalidGroundStationId => {
+                f.write_str("ground station id is empty or contains /, + or #")
+            }
+            InvalidJobId => f.write_str("job id is not an unsigned 64-bit integer"),
+            UnknownTopic => f.write_str("unknown topic"),
+        }
+    }
+}
+
+impl std::error::Error for TopicParseError {}
+
+/// # Topic
+///
+/// A topic of the ground station bus.
+///
+/// | Topic                           | Message            | Direction         |
+/// |---------------------------------|--------------------|-------------------|
+/// | `gs/{id}/jobs`                  | `Job`              | backend → station |
+/// | `gs/{id}/jobs/{job_id}/status`  | `JobStatusUpdate`  | station → backend |
+/// | `gs/{id}/telemetry`             | `TelemetryMessage` | station → backend |
+///
+/// ## Example
+/// ```
+/// use rustar_types::mqtt::topics::{Topic, TopicParseError};
+///
+/// let topic = Topic::job_status("gs-buenos-aires", 12345).unwrap();
+/// assert_eq!(topic.to_string(), "gs/gs-buenos-aires/jobs/12345/status");
+/// assert_eq!("gs/gs-buenos-aires/jobs/12345/status".parse::<Topic>().unwrap(), topic);
+///
+/// // An id with wildcards would match other stations' topics
+/// assert_eq!(Topic::jobs("gs/#"), Err(TopicParseError::InvalidGroundStationId));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Topic {
+    /// Jobs sent to a ground station.
+    Jobs { ground_station_id: GroundStationId },
+    /// Status updates of a job at a ground station.
+    JobStatus {
+        ground_station_id: GroundStationId,
+        job_id: u64,
+    },
+    /// Telemetry downlinked by a ground station.
+    Telemetry { ground_station_id: GroundStationId },
+}
+
+/// # Ground Station Id
+///
+/// A ground station id that can be used as a single topic level: non-empty
+/// and without `/`, `+` or `#`. Serialized as a plain string, and checked
+/// when deserialized.
+///
+/// ## Example
+/// ```
+/// use rustar_types::mqtt::topics::GroundStationId;
+///
+/// assert_eq!(GroundStationId::new("gs-1").unwrap().as_str(), "gs-1");
+/// assert!(GroundStationId::new("").is_err());
+/// assert!(GroundStationId::new("gs/+").is_err());
+///
+/// assert!(serde_json::from_str::<GroundStationId>(r#""gs-1""#).is_ok());
+/// assert!(serde_json::from_str::<GroundStationId>(r#""gs/#""#).is_err());
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "gs-buenos-aires")]
+pub struct GroundStationId(String);
+
+/// The kind of a [`Topic`], without its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TopicKind {
+    Jobs,
+    JobStatus,
+    Telemetry,
+}
+
+impl GroundStationId {
+    pub fn new(id: impl Into<String>) -> Result<Self, TopicParseError> {
+        let id = id.into();
+        if is_valid_ground_station_id(&id) {
+            Ok(GroundStationId(id))
+        } else {
+            Err(TopicParseError::InvalidGroundStationId)
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for GroundStationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for GroundStationId {
+    type Error = TopicParseError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        GroundStationId::new(id)
+    }
+}
+
+impl From<GroundStationId> for String {
+    fn from(id: GroundStationId) -> Self {
+        id.0
+    }
+}
+
+/// Lets maps keyed by station id be looked up with a `&str`.
+impl Borrow<str> for GroundStationId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Topic {
+    pub fn jobs(ground_station_id: impl Into<String>) -> Result<Self, TopicParseError> {
+        Ok(Topic::Jobs {
+            ground_station_id: GroundStationId::new(ground_station_id)?,
+        })
+    }
+
+    pub fn job_status(
+        ground_station_id: impl Into<String>,
+        job_id: u64,
+    ) -> Result<Self, TopicParseError> {
+        Ok(Topic::JobStatus {
+            ground_station_id: GroundStationId::new(ground_station_id)?,
+            job_id,
+        })
+    }
+
+    pub fn telemetry(ground_station_id: impl Into<String>) -> Result<Self, TopicParseError> {
+        Ok(Topic::Telemetry {
+            ground_station_id: GroundStationId::new(ground_station_id)?,
+        })
+    }
+
+    pub fn kind(&self) -> TopicKind {
+        match self {
+            Topic::Jobs { .. } => TopicKind::Jobs,
+            Topic::JobStatus { .. } => TopicKind::JobStatus,
+            Topic::Telemetry { .. } => TopicKind::Telemetry,
+        }
+    }
+
+    pub fn ground_station_id(&self) -> &GroundStationId {
+        match self {
+            Topic::Jobs { ground_station_id }
+            | Topic::JobStatus {
+                ground_station_id, ..
+            }
+            | Topic::Telemetry { ground_station_id } => ground_station_id,
+        }
+    }
+
+    /// The job a [`Topic::JobStatus`] topic is about.
+    pub fn job_id(&self) -> Option<u64> {
+        match self {
+            Topic::JobStatus { job_id, .. } => Some(*job_id),
+            Topic::Jobs { .. } | Topic::Telemetry { .. } => None,
+        }
+    }
+
+    /// Type of the messages published on this topic.
+    pub fn message_type(&self) -> MessageType {
+        self.kind().message_type()
+    }
+}
+
+impl TopicKind {
+    /// Type of the messages published on topics of this kind.
+    pub fn message_type(self) -> MessageType {
+        match self {
+            TopicKind::Jobs => MessageType::Job,
+            TopicKind::JobStatus => MessageType::JobStatusUpdate,
+            TopicKind::Telemetry => MessageType::Telemetry,
+        }
+    }
+
+    /// Subscription filter matching the topics of this kind for one ground
+    /// station, or for all of them if `ground_station_id` is `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::mqtt::topics::{GroundStationId, TopicKind};
+    ///
+    /// let gs_1 = GroundStationId::new("gs-1").unwrap();
+    /// assert_eq!(TopicKind::JobStatus.filter(None), "gs/+/jobs/+/status");
+    /// assert_eq!(TopicKind::Telemetry.filter(Some(&gs_1)), "gs/gs-1/telemetry");
+    /// ```
+    pub fn filter(self, ground_station_id: Option<&GroundStationId>) -> String {
+        let id = ground_station_id.map_or("+", GroundStationId::as_str);
+        match self {
+            TopicKind::Jobs => format!("{ROOT}/{id}/jobs"),
+            TopicKind::JobStatus => format!("{ROOT}/{id}/jobs/+/status"),
+            TopicKind::Telemetry => format!("{ROOT}/{id}/telemetry"),
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Topic::Jobs { ground_station_id } => write!(f, "{ROOT}/{ground_station_id}/jobs"),
+            Topic::JobStatus {
+                ground_station_id,
+                job_id,
+            } => write!(f, "{ROOT}/{ground_station_id}/jobs/{job_id}/status"),
+            Topic::Telemetry { ground_station_id } => {
+                write!(f, "{ROOT}/{ground_station_id}/telemetry")
+            }
+        }
+    }
+}
+
+impl FromStr for Topic {
+    type Err = TopicParseError;
+
+    fn from_str(topic: &str) -> Result<Self, Self::Err> {
+        let mut levels = topic.split('/');
+        if levels.next() != Some(ROOT) {
+            return Err(TopicParseError::InvalidRoot);
+        }
+
+        let ground_station_id = GroundStationId::new(
+            levels
+                .next()
+                .ok_or(TopicParseError::InvalidGroundStationId)?,
+        )?;
+
+        match levels.collect::<Vec<_>>()[..] {
+            ["jobs"] => Ok(Topic::Jobs { ground_station_id }),
+            ["jobs", job_id, "status"] => Ok(Topic::JobStatus {
+                ground_station_id,
+                job_id: job_id.parse().map_err(|_| TopicParseError::InvalidJobId)?,
+            }),
+            ["telemetry"] => Ok(Topic::Telemetry { ground_station_id }),
+            _ => Err(TopicParseError::UnknownTopic),
+        }
+    }
+}
+
+/// Whether `id` can be used as a single topic level.
+pub fn is_valid_ground_station_id(id: &str) -> bool {
+    !id.is_empty() && !id.contains(['/', '+', '#'])
+}
@@ -0,0 +1,286 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Error type for wire encoding and decoding failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum WireError {
+    /// The message could not be serialized
+    Encode(String),
+    /// The bytes are not a valid message of the expected type
+    Decode(String),
+    /// The tagged message is empty
+    MissingTag,
+    /// The tag or content type names no known encoding
+    UnknownContentType,
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use WireError::*;
+        match self {
+            Encode(error) => write!(f, "could not encode message: {error}"),
+            Decode(error) => write!(f, "could not decode message: {error}"),
+            MissingTag => f.write_str("tagged message is empty"),
+            UnknownContentType => f.write_str("unknown content type"),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+/// # Content Type
+///
+/// Encoding of an MQTT message body.
+///
+/// With MQTT 5 the content type travels in the `Content Type` property
+/// (see [`ContentType::mime`]). Over MQTT 3.1.1, which has no message
+/// properties, use [`encode_tagged`] and [`decode_tagged`] to prefix the
+/// body with a one-byte tag instead.
+///
+/// Byte fields such as
+/// [`TelemetryMessage::payload`](super::telemetry::TelemetryMessage) are
+/// arrays of numbers in plain JSON, like serde writes them by default, base64
+/// strings in [`ContentType::Base64Json`] and byte strings in CBOR. Decoding
+/// JSON accepts both arrays and base64 strings.
+///
+/// ## Example
+/// ```
+/// use chrono::Utc;
+/// use rustar_types::mqtt::telemetry::TelemetryMessage;
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::mqtt::wire::{ContentType, decode, encode};
+///
+/// let gs_1 = GroundStationId::new("gs-1").unwrap();
+/// let message = TelemetryMessage::new(gs_1, Utc::now(), b"Hello".to_vec());
+///
+/// let json = String::from_utf8(encode(&message, ContentType::Json).unwrap()).unwrap();
+/// assert!(json.contains(r#""payload":[72,101,108,108,111]"#));
+///
+/// let base64 = encode(&message, ContentType::Base64Json).unwrap();
+/// assert!(String::from_utf8_lossy(&base64).contains(r#""payload":"SGVsbG8=""#));
+///
+/// let decoded: TelemetryMessage = decode(&base64, ContentType::Base64Json).unwrap();
+/// assert_eq!(decoded.payload, message.payload);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// JSON, for readability and interoperability.
+    Json,
+    /// JSON with byte fields as base64 strings, for readable messages with
+    /// large payloads.
+    Base64Json,
+    /// CBOR (RFC 8949), for constrained links.
+    Cbor,
+}
+
+impl ContentType {
+    /// MIME type, for the MQTT 5 `Content Type` property.
+    pub fn mime(self) -> &'static str {
+        match self {
+            ContentType::Json => "application/json",
+            ContentType::Base64Json => "application/vnd.rustar.base64+json",
+            ContentType::Cbor => "application/cbor",
+        }
+    }
+
+    pub fn from_mime(mime: &str) -> Result<Self, WireError> {
+        match mime {
+            "application/json" => Ok(ContentType::Json),
+            "application/vnd.rustar.base64+json" => Ok(ContentType::Base64Json),
+            "application/cbor" => Ok(ContentType::Cbor),
+            _ => Err(WireError::UnknownContentType),
+        }
+    }
+
+    /// Prefix byte of tagged messages.
+    pub fn tag(self) -> u8 {
+        match self {
+            ContentType::Json => 0x01,
+            ContentType::Cbor => 0x02,
+            ContentType::Base64Json => 0x03,
+        }
+    }
+
+    pub fn from_tag(tag: u8) -> Result<Self, WireError> {
+        match tag {
+            0x01 => Ok(ContentType::Json),
+            0x02 => Ok(ContentType::Cbor),
+            0x03 => Ok(ContentType::Base64Json),
+            _ => Err(WireError::UnknownContentType),
+        }
+    }
+}
+
+/// Encodes a message body.
+pub fn encode<T: Serialize>(message: &T, content_type: ContentType) -> Result<Vec<u8>, WireError> {
+    let encode = |error: String| WireError::Encode(error);
+    match content_type {
+        ContentType::Json => serde_json::to_vec(message).map_err(|e| encode(e.to_string())),
+        ContentType::Base64Json => {
+            let mut bytes = Vec::new();
+            let mut serializer =
+                serde_json::Serializer::with_formatter(&mut bytes, Base64Formatter);
+            message
+                .serialize(&mut serializer)
+                .map_err(|e| encode(e.to_string()))?;
+            Ok(bytes)
+        }
+        ContentType::Cbor => {
+            let mut bytes = Vec::new();
+            ciborium::into_writer(message, &mut bytes).map_err(|e| encode(e.to_string()))?;
+            Ok(bytes)
+        }
+    }
+}
+
+/// Decodes a message body.
+pub fn decode<T: DeserializeOwned>(
+    bytes: &[u8],
+    content_type: ContentType,
+) -> Result<T, WireError> {
+    let decode = |error: String| WireError::Decode(error);
+    match content_type {
+        ContentType::Json | ContentType::Base64Json => {
+            serde_json::from_slice(bytes).map_err(|e| decode(e.to_string()))
+        }
+        ContentType::Cbor => ciborium::from_reader(bytes).map_err(|e| decode(e.to_string())),
+    }
+}
+
+/// Encodes a message body prefixed with its content type tag.
+///
+/// ## Example
+/// ```
+/// use chrono::Utc;
+/// use rustar_types::mqtt::envelope::Envelope;
+/// use rustar_types::mqtt::telemetry::TelemetryMessage;
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::mqtt::wire::{ContentType, decode_tagged, encode_tagged};
+///
+/// let gs_1 = GroundStationId::new("gs-1").unwrap();
+/// let message = Envelope::new(TelemetryMessage::new(gs_1, Utc::now(), vec![0xAB; 256]));
+///
+/// let json = encode_tagged(&message, ContentType::Json).unwrap();
+/// let cbor = encode_tagged(&message, ContentType::Cbor).unwrap();
+/// assert!(cbor.len() < json.len());
+///
+/// let (decoded, content_type): (Envelope<TelemetryMessage>, _) = decode_tagged(&cbor).unwrap();
+/// assert_eq!(content_type, ContentType::Cbor);
+/// assert_eq!(decoded.payload.payload, message.payload.payload);
+/// ```
+pub fn encode_tagged<T: Serialize>(
+    message: &T,
+    content_type: ContentType,
+) -> Result<Vec<u8>, WireError> {
+    let mut bytes = vec![content_type.tag()];
+    bytes.extend(encode(message, content_type)?);
+    Ok(bytes)
+}
+
+/// Decodes a message body prefixed with its content type tag.
+pub fn decode_tagged<T: DeserializeOwned>(bytes: &[u8]) -> Result<(T, ContentType), WireError> {
+    let (&tag, body) = bytes.split_first().ok_or(WireError::MissingTag)?;
+    let content_type = ContentType::from_tag(tag)?;
+    Ok((decode(body, content_type)?, content_type))
+}
+
+/// Compact JSON formatter writing byte fields as base64 strings.
+struct Base64Formatter;
+
+impl serde_json::ser::Formatter for Base64Formatter {
+    fn write_byte_array<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: &[u8],
+    ) -> std::io::Result<()> {
+        use base64::Engine;
+        // The base64 alphabet needs no JSON escaping
+        let encoded = base64::engine::general_purpose::STANDARD.encode(value);
+        write!(writer, "\"{encoded}\"")
+    }
+}
+
+/// Serde adapter for byte fields: serialized as bytes, which JSON writes as
+/// an array of numbers (or base64 with [`ContentType::Base64Json`]) and CBOR
+/// as a byte string. Deserialization accepts any of these.
+pub(crate) mod bytes {
+    use std::fmt;
+
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(bytes)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_any(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a base64 string, a byte string or an array of bytes")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Vec<u8>, E> {
+            STANDARD.decode(value).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(value.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(value)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+
+    /// [`bytes`](self) for optional fields, which also need
+    /// `#[serde(default)]`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            bytes: &Option<Vec<u8>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => serializer.serialize_some(&Bytes(bytes)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<u8>>, D::Error> {
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+        }
+
+        struct Bytes<'a>(&'a [u8]);
+
+        impl serde::Serialize for Bytes<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(self.0, serializer)
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super")] Vec<u8>);
+    }
+}
@@ -0,0 +1,282 @@
+//! # Pass Prediction
+//!
+//! Finds the time windows in which a satellite is visible from a ground
+//! station above a minimum elevation, so jobs don't need their AOS/LOS
+//! filled in by hand.
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::frames::Geodetic;
+use crate::jobs::JobBuilder;
+use crate::sgp4::{Sgp4, Sgp4Error};
+
+/// Time between elevation samples while searching for passes.
+const SEARCH_STEP_SECONDS: i64 = 30;
+/// Precision of the AOS, LOS and TCA times.
+const TIME_TOLERANCE_MILLISECONDS: i64 = 100;
+
+/// # Satellite Pass
+///
+/// A single visibility window of a satellite over a ground station.
+///
+/// Passes already in progress at the start of the search interval, or still
+/// in progress at its end, are clipped to the interval.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "aos": "2025-09-19T12:00:00Z",
+///   "tca": "2025-09-19T12:05:12Z",
+///   "los": "2025-09-19T12:10:31Z",
+///   "max_elevation": 47.2,
+///   "aos_azimuth": 212.4,
+///   "tca_azimuth": 131.9,
+///   "los_azimuth": 48.7
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct Pass {
+    /// *Acquisition of Signal*: the satellite rises above the elevation mask.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub aos: DateTime<Utc>,
+    /// *Time of Closest Approach*: the satellite reaches its maximum elevation.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:05:12Z")]
+    pub tca: DateTime<Utc>,
+    /// *Loss of Signal*: the satellite sets below the elevation mask.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:10:31Z")]
+    pub los: DateTime<Utc>,
+    /// Maximum elevation reached during the pass, in degrees.
+    #[schema(example = 47.2)]
+    pub max_elevation: f64,
+    /// Azimuth at AOS, in degrees.
+    #[schema(example = 212.4)]
+    pub aos_azimuth: f64,
+    /// Azimuth at TCA, in degrees.
+    #[schema(example = 131.9)]
+    pub tca_azimuth: f64,
+    /// Azimuth at LOS, in degrees.
+    #[schema(example = 48.7)]
+    pub los_azimuth: f64,
+}
+
+impl Pass {
+    /// Duration of the pass, from AOS to LOS.
+    pub fn duration(&self) -> Duration {
+        self.los - self.aos
+    }
+}
+
+/// Predicts all passes of the satellite followed by `propagator` over
+/// `observer` between `start` and `end`.
+///
+/// Only the parts of each pass above `min_elevation` (in degrees) are
+/// reported. The propagator can come from a TLE set, an OMM or a job's
+/// [`OrbitData`](crate::jobs::OrbitData).
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::{Job, TleData};
+/// use rustar_types::passes::predict_passes;
+///
+/// let tle = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let propagator = tle.propagator().unwrap();
+/// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+///
+/// let passes =
+///     predict_passes(&propagator, &buenos_aires, 10.0, start, start + Duration::days(1)).unwrap();
+/// assert!(!passes.is_empty());
+///
+/// for pass in &passes {
+///     assert!(pass.aos <= pass.tca && pass.tca <= pass.los);
+///     assert!(pass.max_elevation >= 10.0);
+/// }
+///
+/// // Track the first pass
+/// let job = Job::builder(12345)
+///     .tle(tle)
+///     .pass(&passes[0])
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(job.start, passes[0].aos);
+/// ```
+pub fn predict_passes(
+    propagator: &Sgp4,
+    observer: &Geodetic,
+    min_elevation: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Pass>, Sgp4Error> {
+    PassFinder {
+        propagator,
+        observer,
+        min_elevation,
+    }
+    .find(start, end)
+}
+
+/// Searches the elevation curve of a single satellite and observer.
+struct PassFinder<'a> {
+    propagator: &'a Sgp4,
+    observer: &'a Geodetic,
+    min_elevation: f64,
+}
+
+impl PassFinder<'_> {
+    fn elevation(&self, time: DateTime<Utc>) -> Result<f64, Sgp4Error> {
+        Ok(self.propagator.observe(self.observer, time)?.elevation)
+    }
+
+    fn find(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Pass>, Sgp4Error> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        let step = Duration::seconds(SEARCH_STEP_SECONDS);
+        let mut samples = Vec::new();
+        let mut time = start;
+        while time < end {
+            samples.push((time, self.elevation(time)?));
+            time += step;
+        }
+        samples.push((end, self.elevation(end)?));
+
+        let mut windows = Vec::new();
+        let mut rise = (samples[0].1 >= self.min_elevation).then_some(start);
+
+        for i in 1..samples.len() {
+            let (previous_time, previous) = samples[i - 1];
+            let (current_time, current) = samples[i];
+
+            match rise {
+                None if current >= self.min_elevation => {
+                    rise = Some(self.crossing(previous_time, current_time)?);
+                }
+                None => {
+                    // A short pass can peak above the mask between two samples
+                    // that are both below it.
+                    let Some(&(next_time, next)) = samples.get(i + 1) else {
+                        continue;
+                    };
+                    if previous < current && current >= next {
+                        let (peak_time, peak) = self.maximum(previous_time, next_time)?;
+                        if peak >= self.min_elevation {
+                            windows.push((
+                                self.crossing(previous_time, peak_time)?,
+                                self.crossing(peak_time, next_time)?,
+                            ));
+                        }
+                    }
+                }
+                Some(aos) if current < self.min_elevation => {
+                    windows.push((aos, self.crossing(previous_time, current_time)?));
+                    rise = None;
+                }
+                Some(_) => {}
+            }
+        }
+        if let Some(aos) = rise {
+            windows.push((aos, end));
+        }
+
+        windows
+            .into_iter()
+            .filter(|(aos, los)| aos < los)
+            .map(|(aos, los)| self.pass(aos, los))
+            .collect()
+    }
+
+    fn pass(&self, aos: DateTime<Utc>, los: DateTime<Utc>) -> Result<Pass, Sgp4Error> {
+        let (tca, max_elevation) = self.maximum(aos, los)?;
+        let (aos, tca, los) = (
+            aos.round_subsecs(3),
+            tca.round_subsecs(3),
+            los.round_subsecs(3),
+        );
+
+        Ok(Pass {
+            aos,
+            tca,
+            los,
+            max_elevation,
+            aos_azimuth: self.propagator.observe(self.observer, aos)?.azimuth,
+            tca_azimuth: self.propagator.observe(self.observer, tca)?.azimuth,
+            los_azimuth: self.propagator.observe(self.observer, los)?.azimuth,
+        })
+    }
+
+    /// Bisects the time at which the elevation crosses the mask between `a`
+    /// and `b`, which must lie on opposite sides of it.
+    fn crossing(
+        &self,
+        mut a: DateTime<Utc>,
+        mut b: DateTime<Utc>,
+    ) -> Result<DateTime<Utc>, Sgp4Error> {
+        let a_above = self.elevation(a)? >= self.min_elevation;
+        while b - a > Duration::milliseconds(TIME_TOLERANCE_MILLISECONDS) {
+            let middle = a + (b - a) / 2;
+            if (self.elevation(middle)? >= self.min_elevation) == a_above {
+                a = middle;
+            } else {
+                b = middle;
+            }
+        }
+        // Return the side that is above the mask, so the window never
+        // includes time below it.
+        Ok(if a_above { a } else { b })
+    }
+
+    /// Golden-section search for the maximum elevation between `a` and `b`.
+    fn maximum(
+        &self,
+        mut a: DateTime<Utc>,
+        mut b: DateTime<Utc>,
+    ) -> Result<(DateTime<Utc>, f64), Sgp4Error> {
+        const INVERSE_PHI: f64 = 0.618_033_988_749_894_8;
+
+        let split = |a: DateTime<Utc>, b: DateTime<Utc>, ratio: f64| {
+            let span = (b - a).num_milliseconds() as f64;
+            a + Duration::milliseconds((span * ratio).round() as i64)
+        };
+
+        let mut c = split(a, b, 1.0 - INVERSE_PHI);
+        let mut d = split(a, b, INVERSE_PHI);
+        let mut fc = self.elevation(c)?;
+        let mut fd = self.elevation(d)?;
+
+        while b - a > Duration::milliseconds(TIME_TOLERANCE_MILLISECONDS) {
+            if fc > fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = split(a, b, 1.0 - INVERSE_PHI);
+                fc = self.elevation(c)?;
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = split(a, b, INVERSE_PHI);
+                fd = self.elevation(d)?;
+            }
+        }
+
+        let tca = a + (b - a) / 2;
+        Ok((tca, self.elevation(tca)?))
+    }
+}
+
+impl JobBuilder {
+    /// Sets the tracking window from a predicted pass (`start` = AOS,
+    /// `end` = LOS).
+    pub fn pass(self, pass: &Pass) -> Self {
+        self.window(pass.aos, pass.los)
+    }
+}
@@ -0,0 +1,425 @@
+//! # Scheduler
+//!
+//! Turns a set of satellites to track into conflict-free [`Job`]s across
+//! several ground stations, dropping lower priority passes when they
+//! overlap and explaining why each pass was dropped.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::jobs::{Job, JobBuildError, JobValidator, JobViolation, OrbitData, TleCatalog};
+use crate::mqtt::topics::GroundStationId;
+use crate::passes::{Pass, predict_passes};
+use crate::stations::GroundStation;
+
+/// # Satellite Request
+///
+/// A satellite to track, with the radio settings of its jobs.
+#[derive(Debug, Clone)]
+pub struct SatelliteRequest {
+    pub satellite_id: String,
+    /// TLE set or OMM of the satellite.
+    pub orbit: OrbitData,
+    /// Passes of higher priority satellites are scheduled first.
+    pub priority: u32,
+    /// Downlink frequency, in Hertz.
+    pub rx_frequency: f64,
+    /// Uplink frequency, in Hertz.
+    pub tx_frequency: f64,
+    /// Data to transmit on every pass, if any.
+    pub uplink: Option<Vec<u8>>,
+}
+
+impl SatelliteRequest {
+    /// A downlink-only request for the satellite with NORAD catalog number
+    /// `norad_id`, using its newest TLE in `catalog`. The satellite id is
+    /// the TLE name, or the catalog number if the TLE has none.
+    pub fn from_catalog(
+        catalog: &TleCatalog,
+        norad_id: u32,
+        priority: u32,
+        rx_frequency: f64,
+        tx_frequency: f64,
+    ) -> Option<Self> {
+        let tle = catalog.get(norad_id)?.clone();
+        let satellite_id = match tle.tle0.as_str() {
+            "" => norad_id.to_string(),
+            name => name.to_string(),
+        };
+        Some(SatelliteRequest {
+            satellite_id,
+            orbit: OrbitData::Tle(tle),
+            priority,
+            rx_frequency,
+            tx_frequency,
+            uplink: None,
+        })
+    }
+}
+
+/// Why a pass was left out of the schedule.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub enum DropReason {
+    /// The satellite request does not make a valid job. `reason` describes
+    /// the [`JobBuildError`](crate::jobs::JobBuildError).
+    InvalidRequest { reason: String },
+    /// The pass is shorter than the scheduler's minimum.
+    TooShort { seconds: i64 },
+    /// The station cannot execute the job (see [`JobValidator`]).
+    Infeasible { violations: Vec<JobViolation> },
+    /// The pass overlaps, or leaves no time to set up and slew after or
+    /// before, a pass that was scheduled first.
+    Conflict {
+        /// Satellite of the scheduled pass.
+        satellite_id: String,
+        /// Priority of the scheduled pass.
+        priority: u32,
+    },
+    /// Passes could not be predicted for the satellite. `reason` describes
+    /// the [`Sgp4Error`](crate::sgp4::Sgp4Error).
+    PropagationFailed { reason: String },
+    /// The station description is inconsistent, so none of its passes are
+    /// scheduled. `reason` describes the
+    /// [`GroundStationError`](crate::stations::GroundStationError).
+    InvalidStation { reason: String },
+}
+
+/// A pass that was not scheduled.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct DroppedPass {
+    pub ground_station_id: GroundStationId,
+    pub satellite_id: String,
+    /// The dropped pass, or `None` if no passes could be predicted or the
+    /// station is invalid.
+    pub pass: Option<Pass>,
+    pub reason: DropReason,
+}
+
+/// # Schedule
+///
+/// Output of [`Scheduler::schedule`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Schedule {
+    /// Jobs of every station, by station id, in chronological order.
+    pub jobs: BTreeMap<GroundStationId, Vec<Job>>,
+    /// Passes left out, with the reason why.
+    pub dropped: Vec<DroppedPass>,
+}
+
+/// # Scheduler
+///
+/// Schedules passes greedily by priority: higher priority passes are
+/// placed first, ties going to the earlier and then the higher pass. A pass
+/// is dropped if its station is busy, including the setup time and the
+/// rotator slew from the previous pass's LOS to its AOS (or from its LOS to
+/// the next pass's AOS).
+///
+/// The slew takes as long as the slower of the two axes. The azimuth move
+/// goes the shorter way around north only if the rotator's azimuth range
+/// reaches both positions that way; the elevation move is between the
+/// effective elevation masks at both azimuths. Stations that fail
+/// [`GroundStation::validate`] get no jobs.
+///
+/// Passes are predicted above each station's minimum elevation mask; the
+/// horizon profile is enforced through the [`JobValidator`].
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::TleData;
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::scheduler::{SatelliteRequest, Scheduler};
+/// use rustar_types::stations::{FrequencyBand, GroundStation};
+///
+/// let iss = TleData::try_from("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648".to_string()).unwrap();
+/// let satellites = vec![SatelliteRequest {
+///     satellite_id: "ISS".to_string(),
+///     orbit: iss.into(),
+///     priority: 1,
+///     rx_frequency: 145_800_000.0,
+///     tx_frequency: 437_500_000.0,
+///     uplink: None,
+/// }];
+///
+/// let id = GroundStationId::new("gs-buenos-aires").unwrap();
+/// let mut station = GroundStation::new(id, Geodetic::new(-34.6037, -58.3816, 0.025));
+/// station.bands.push(FrequencyBand::receive_only(144_000_000.0, 146_000_000.0));
+///
+/// let start = Utc.with_ymd_and_hms(2025, 8, 24, 0, 0, 0).unwrap();
+/// let schedule = Scheduler::default().schedule(&satellites, &[station], start, start + Duration::days(1));
+///
+/// let jobs = &schedule.jobs["gs-buenos-aires"];
+/// assert!(!jobs.is_empty());
+/// for pair in jobs.windows(2) {
+///     assert!(pair[0].end < pair[1].start);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    /// Time a station needs between two passes, besides slewing.
+    pub setup_time: Duration,
+    /// Passes shorter than this are not scheduled.
+    pub min_pass_duration: Duration,
+    /// Checks every candidate job against its station.
+    pub validator: JobValidator,
+    /// Id of the first scheduled job; the rest follow consecutively.
+    pub first_job_id: u64,
+}
+
+impl Default for Scheduler {
+    /// Two minutes of setup time and passes of at least one minute.
+    fn default() -> Self {
+        Scheduler {
+            setup_time: Duration::minutes(2),
+            min_pass_duration: Duration::minutes(1),
+            validator: JobValidator::default(),
+            first_job_id: 1,
+        }
+    }
+}
+
+/// A pass that may be scheduled.
+struct Candidate<'a> {
+    station: &'a GroundStation,
+    satellite: &'a SatelliteRequest,
+    pass: Pass,
+    /// The job tracking the pass, numbered once scheduled.
+    job: Job,
+}
+
+impl Scheduler {
+    /// Schedules the passes of `satellites` over `stations` between
+    /// `start` and `end`.
+    pub fn schedule(
+        &self,
+        satellites: &[SatelliteRequest],
+        stations: &[GroundStation],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Schedule {
+        let mut dropped = Vec::new();
+        let mut candidates = Vec::new();
+
+        let mut valid_stations = Vec::new();
+        for station in stations {
+            match station.validate() {
+                Ok(()) => valid_stations.push(station),
+                Err(error) => dropped.extend(satellites.iter().map(|satellite| DroppedPass {
+                    ground_station_id: station.id.clone(),
+                    satellite_id: satellite.satellite_id.clone(),
+                    pass: None,
+                    reason: DropReason::InvalidStation {
+                        reason: error.to_string(),
+                    },
+                })),
+            }
+        }
+
+        let propagators: Vec<_> = satellites
+            .iter()
+            .map(|satellite| satellite.orbit.propagator())
+            .collect();
+
+        for &station in &valid_stations {
+            for (satellite, propagator) in satellites.iter().zip(&propagators) {
+                let passes = propagator
+                    .as_ref()
+                    .map_err(Clone::clone)
+                    .and_then(|propagator| {
+                        predict_passes(
+                            propagator,
+                            &station.location,
+                            station.elevation_mask.minimum,
+                            start,
+                            end,
+                        )
+                    });
+                let passes = match passes {
+                    Ok(passes) => passes,
+                    Err(error) => {
+                        dropped.push(DroppedPass {
+                            ground_station_id: station.id.clone(),
+                            satellite_id: satellite.satellite_id.clone(),
+                            pass: None,
+                            reason: DropReason::PropagationFailed {
+                                reason: error.to_string(),
+                            },
+                        });
+                        continue;
+                    }
+                };
+
+                for pass in passes {
+                    let drop = |reason| DroppedPass {
+                        ground_station_id: station.id.clone(),
+                        satellite_id: satellite.satellite_id.clone(),
+                        pass: Some(pass.clone()),
+                        reason,
+                    };
+
+                    if pass.duration() < self.min_pass_duration {
+                        dropped.push(drop(DropReason::TooShort {
+                            seconds: pass.duration().num_seconds(),
+                        }));
+                        continue;
+                    }
+                    let job = match job(0, satellite, &pass) {
+                        Ok(job) => job,
+                        Err(error) => {
+                            dropped.push(drop(DropReason::InvalidRequest {
+                                reason: error.to_string(),
+                            }));
+                            continue;
+                        }
+                    };
+                    let report = self.validator.validate(&job, station, start);
+                    if !report.is_feasible() {
+                        dropped.push(drop(DropReason::Infeasible {
+                            violations: report.violations,
+                        }));
+                        continue;
+                    }
+
+                    candidates.push(Candidate {
+                        station,
+                        satellite,
+                        pass,
+                        job,
+                    });
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| {
+            b.satellite
+                .priority
+                .cmp(&a.satellite.priority)
+                .then(a.pass.aos.cmp(&b.pass.aos))
+                .then(b.pass.max_elevation.total_cmp(&a.pass.max_elevation))
+        });
+
+        let mut accepted: BTreeMap<&GroundStationId, Vec<Candidate>> = BTreeMap::new();
+        for station in &valid_stations {
+            accepted.entry(&station.id).or_default();
+        }
+
+        for candidate in candidates {
+            let station_passes = accepted.entry(&candidate.station.id).or_default();
+            let conflict = station_passes
+                .iter()
+                .find(|scheduled| self.conflicts(candidate.station, scheduled, &candidate));
+
+            match conflict {
+                Some(scheduled) => dropped.push(DroppedPass {
+                    ground_station_id: candidate.station.id.clone(),
+                    satellite_id: candidate.satellite.satellite_id.clone(),
+                    pass: Some(candidate.pass),
+                    reason: DropReason::Conflict {
+                        satellite_id: scheduled.satellite.satellite_id.clone(),
+                        priority: scheduled.satellite.priority,
+                    },
+                }),
+                None => station_passes.push(candidate),
+            }
+        }
+
+        let mut id = self.first_job_id;
+        let jobs = accepted
+            .into_iter()
+            .map(|(station_id, mut passes)| {
+                passes.sort_by_key(|candidate| candidate.pass.aos);
+                let jobs = passes
+                    .into_iter()
+                    .map(|candidate| {
+                        let mut job = candidate.job;
+                        job.id = id;
+                        id += 1;
+                        job
+                    })
+                    .collect();
+                (station_id.clone(), jobs)
+            })
+            .collect();
+
+        Schedule { jobs, dropped }
+    }
+
+    /// Whether two passes at `station` are too close to both be tracked.
+    fn conflicts(&self, station: &GroundStation, a: &Candidate, b: &Candidate) -> bool {
+        let (first, second) = if a.pass.aos <= b.pass.aos {
+            (&a.pass, &b.pass)
+        } else {
+            (&b.pass, &a.pass)
+        };
+
+        let ready = slew_time(station, first.los_azimuth, second.aos_azimuth)
+            .and_then(|slew| self.setup_time.checked_add(&slew))
+            .and_then(|busy| first.los.checked_add_signed(busy));
+        match ready {
+            Some(ready) => ready > second.aos,
+            // Too long to represent, so certainly too long to fit
+            None => true,
+        }
+    }
+}
+
+/// Time for the rotator of `station` to move from `from` to `to` azimuth
+/// (degrees), waiting at the elevation mask in both directions. `None` if
+/// the slew is too long to represent.
+fn slew_time(station: &GroundStation, from: f64, to: f64) -> Option<Duration> {
+    let rotator = &station.rotator;
+    let elevation = |azimuth| {
+        station
+            .elevation_mask
+            .at(azimuth)
+            .clamp(rotator.min_elevation, rotator.max_elevation)
+    };
+
+    let azimuth_seconds = azimuth_travel(station, from, to) / rotator.azimuth_rate;
+    let elevation_seconds = (elevation(to) - elevation(from)).abs() / rotator.elevation_rate;
+    let milliseconds = (azimuth_seconds.max(elevation_seconds) * 1000.0).ceil();
+
+    if !(0.0..i64::MAX as f64).contains(&milliseconds) {
+        return None;
+    }
+    Duration::try_milliseconds(milliseconds as i64)
+}
+
+/// Shortest azimuth move, in degrees, between `from` and `to` within the
+/// rotator's azimuth range. Rotators whose range spans more than a turn can
+/// reach some azimuths two ways, and only those can cross north.
+fn azimuth_travel(station: &GroundStation, from: f64, to: f64) -> f64 {
+    let rotator = &station.rotator;
+    let positions = |azimuth: f64| {
+        let azimuth = azimuth.rem_euclid(360.0);
+        (-2..=2)
+            .map(move |turns| azimuth + 360.0 * f64::from(turns))
+            .filter(|position| (rotator.min_azimuth..=rotator.max_azimuth).contains(position))
+    };
+
+    positions(from)
+        .flat_map(|from| positions(to).map(move |to| (to - from).abs()))
+        .min_by(f64::total_cmp)
+        // Passes outside the range are dropped as infeasible anyway
+        .unwrap_or_else(|| (to.rem_euclid(360.0) - from.rem_euclid(360.0)).abs())
+}
+
+/// The job tracking `pass` of `satellite`.
+fn job(id: u64, satellite: &SatelliteRequest, pass: &Pass) -> Result<Job, JobBuildError> {
+    let mut job = Job::builder(id)
+        .satellite_id(&satellite.satellite_id)
+        .orbit(satellite.orbit.clone())
+        .pass(pass)
+        .rx_frequency(satellite.rx_frequency)
+        .tx_frequency(satellite.tx_frequency);
+    if let Some(uplink) = &satellite.uplink {
+        job = job.uplink(uplink.clone());
+    }
+    job.build()
+}
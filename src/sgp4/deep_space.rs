@@ -0,0 +1,696 @@
+//! Deep-space (SDP4) extensions of the SGP4 model.
+//!
+//! Satellites with an orbital period of 225 minutes or more are additionally
+//! perturbed by the Sun and the Moon (`dscom` / `dpper`) and, for 12 and 24
+//! hour orbits, by Earth geopotential resonances (`dsinit` / `dspace`).
+//! Function and variable names follow Vallado's reference implementation so
+//! both can be compared side by side.
+
+use std::f64::consts::{PI, TAU};
+
+use super::XKE;
+
+const ZES: f64 = 0.01675;
+const ZEL: f64 = 0.05490;
+const ZNS: f64 = 1.19459e-5;
+const ZNL: f64 = 1.5835218e-4;
+/// Earth rotation rate, in radians per minute.
+const RPTIM: f64 = 4.375_269_088_011_3e-3;
+
+/// Mean elements updated in place by the deep-space secular terms.
+pub(super) struct MeanElements {
+    pub em: f64,
+    pub argpm: f64,
+    pub inclm: f64,
+    pub mm: f64,
+    pub nodem: f64,
+    pub nm: f64,
+}
+
+/// Osculating elements updated in place by the lunar-solar periodics.
+pub(super) struct PeriodicElements {
+    pub ep: f64,
+    pub inclp: f64,
+    pub nodep: f64,
+    pub argpp: f64,
+    pub mp: f64,
+}
+
+/// Epoch state needed to initialize the deep-space terms.
+pub(super) struct Epoch {
+    /// Days since 1949 December 31 00:00 UT.
+    pub epoch: f64,
+    pub ecco: f64,
+    pub eccsq: f64,
+    pub argpo: f64,
+    pub inclo: f64,
+    pub nodeo: f64,
+    pub mo: f64,
+    pub no: f64,
+    pub mdot: f64,
+    pub nodedot: f64,
+    pub xpidot: f64,
+    pub gsto: f64,
+}
+
+/// Precomputed deep-space coefficients.
+#[derive(Debug)]
+pub(super) struct DeepSpace {
+    // Lunar-solar periodic coefficients
+    e3: f64,
+    ee2: f64,
+    se2: f64,
+    se3: f64,
+    sgh2: f64,
+    sgh3: f64,
+    sgh4: f64,
+    sh2: f64,
+    sh3: f64,
+    si2: f64,
+    si3: f64,
+    sl2: f64,
+    sl3: f64,
+    sl4: f64,
+    xgh2: f64,
+    xgh3: f64,
+    xgh4: f64,
+    xh2: f64,
+    xh3: f64,
+    xi2: f64,
+    xi3: f64,
+    xl2: f64,
+    xl3: f64,
+    xl4: f64,
+    zmol: f64,
+    zmos: f64,
+
+    // Secular rates
+    dedt: f64,
+    didt: f64,
+    dmdt: f64,
+    dnodt: f64,
+    domdt: f64,
+
+    // Resonance terms
+    resonance: Resonance,
+    xfact: f64,
+    xlamo: f64,
+    gsto: f64,
+}
+
+/// Geopotential resonance regime of the orbit.
+#[derive(Debug)]
+enum Resonance {
+    None,
+    /// One-day (geosynchronous) orbits.
+    Synchronous {
+        del1: f64,
+        del2: f64,
+        del3: f64,
+    },
+    /// Half-day, highly eccentric (Molniya-type) orbits.
+    HalfDay(Box<HalfDayTerms>),
+}
+
+#[derive(Debug)]
+struct HalfDayTerms {
+    d2201: f64,
+    d2211: f64,
+    d3210: f64,
+    d3222: f64,
+    d4410: f64,
+    d4422: f64,
+    d5220: f64,
+    d5232: f64,
+    d5421: f64,
+    d5433: f64,
+}
+
+impl DeepSpace {
+    /// Initializes the deep-space terms (`dscom` followed by `dsinit`).
+    pub(super) fn new(epoch: &Epoch) -> Self {
+        const ZSINIS: f64 = 0.397_854_16;
+        const ZCOSIS: f64 = 0.917_448_67;
+        const ZCOSGS: f64 = 0.194_590_5;
+        const ZSINGS: f64 = -0.980_884_58;
+        const C1SS: f64 = 2.986_479_7e-6;
+        const C1L: f64 = 4.796_806_5e-7;
+
+        // dscom
+        let nm = epoch.no;
+        let em = epoch.ecco;
+        let snodm = epoch.nodeo.sin();
+        let cnodm = epoch.nodeo.cos();
+        let sinomm = epoch.argpo.sin();
+        let cosomm = epoch.argpo.cos();
+        let sinim = epoch.inclo.sin();
+        let cosim = epoch.inclo.cos();
+        let emsq = em * em;
+        let betasq = 1.0 - emsq;
+        let rtemsq = betasq.sqrt();
+
+        let day = epoch.epoch + 18261.5;
+        let xnodce = (4.523_602_0 - 9.242_202_9e-4 * day) % TAU;
+        let stem = xnodce.sin();
+        let ctem = xnodce.cos();
+        let zcosil = 0.913_751_64 - 0.035_680_96 * ctem;
+        let zsinil = (1.0 - zcosil * zcosil).sqrt();
+        let zsinhl = 0.089_683_511 * stem / zsinil;
+        let zcoshl = (1.0 - zsinhl * zsinhl).sqrt();
+        let gam = 5.835_151_4 + 0.001_944_368_0 * day;
+        let zx = 0.397_854_16 * stem / zsinil;
+        let zy = zcoshl * ctem + 0.917_448_67 * zsinhl * stem;
+        let zx = gam + zx.atan2(zy) - xnodce;
+        let zcosgl = zx.cos();
+        let zsingl = zx.sin();
+
+        // Solar terms first, then lunar terms
+        let solar = ThirdBody::new(
+            ThirdBodyGeometry {
+                zcosg: ZCOSGS,
+                zsing: ZSINGS,
+                zcosi: ZCOSIS,
+                zsini: ZSINIS,
+                zcosh: cnodm,
+                zsinh: snodm,
+                cc: C1SS,
+            },
+            sinim,
+            cosim,
+            sinomm,
+            cosomm,
+            em,
+            emsq,
+            betasq,
+            rtemsq,
+            nm,
+        );
+        let lunar = ThirdBody::new(
+            ThirdBodyGeometry {
+                zcosg: zcosgl,
+                zsing: zsingl,
+                zcosi: zcosil,
+                zsini: zsinil,
+                zcosh: zcoshl * cnodm + zsinhl * snodm,
+                zsinh: snodm * zcoshl - cnodm * zsinhl,
+                cc: C1L,
+            },
+            sinim,
+            cosim,
+            sinomm,
+            cosomm,
+            em,
+            emsq,
+            betasq,
+            rtemsq,
+            nm,
+        );
+
+        let zmol = (4.719_967_2 + 0.229_971_50 * day - gam) % TAU;
+        let zmos = (6.256_583_7 + 0.017_201_977 * day) % TAU;
+
+        let ss = &solar;
+        let s = &lunar;
+
+        // dsinit
+        let inclm = epoch.inclo;
+        let near_equatorial = !(5.235_987_7e-2..=PI - 5.235_987_7e-2).contains(&inclm);
+
+        let ses = ss.s1 * ZNS * ss.s5;
+        let sis = ss.s2 * ZNS * (ss.z11 + ss.z13);
+        let sls = -ZNS * ss.s3 * (ss.z1 + ss.z3 - 14.0 - 6.0 * emsq);
+        let sghs = ss.s4 * ZNS * (ss.z31 + ss.z33 - 6.0);
+        let mut shs = -ZNS * ss.s2 * (ss.z21 + ss.z23);
+        if near_equatorial {
+            shs = 0.0;
+        }
+        if sinim != 0.0 {
+            shs /= sinim;
+        }
+        let sgs = sghs - cosim * shs;
+
+        let dedt = ses + s.s1 * ZNL * s.s5;
+        let didt = sis + s.s2 * ZNL * (s.z11 + s.z13);
+        let dmdt = sls - ZNL * s.s3 * (s.z1 + s.z3 - 14.0 - 6.0 * emsq);
+        let sghl = s.s4 * ZNL * (s.z31 + s.z33 - 6.0);
+        let mut shll = -ZNL * s.s2 * (s.z21 + s.z23);
+        if near_equatorial {
+            shll = 0.0;
+        }
+        let mut domdt = sgs + sghl;
+        let mut dnodt = shs;
+        if sinim != 0.0 {
+            domdt -= cosim / sinim * shll;
+            dnodt += shll / sinim;
+        }
+
+        let theta = epoch.gsto % TAU;
+        let aonv = (nm / XKE).powf(2.0 / 3.0);
+
+        let (resonance, xfact, xlamo) = if nm < 0.005_235_987_7 && nm > 0.003_490_658_5 {
+            const Q22: f64 = 1.789_167_9e-6;
+            const Q31: f64 = 2.146_074_8e-6;
+            const Q33: f64 = 2.212_301_5e-7;
+
+            let g200 = 1.0 + emsq * (-2.5 + 0.8125 * emsq);
+            let g310 = 1.0 + 2.0 * emsq;
+            let g300 = 1.0 + emsq * (-6.0 + 6.609_37 * emsq);
+            let f220 = 0.75 * (1.0 + cosim) * (1.0 + cosim);
+            let f311 = 0.9375 * sinim * sinim * (1.0 + 3.0 * cosim) - 0.75 * (1.0 + cosim);
+            let f330 = 1.875 * (1.0 + cosim).powi(3);
+            let del1 = 3.0 * nm * nm * aonv * aonv;
+            let del2 = 2.0 * del1 * f220 * g200 * Q22;
+            let del3 = 3.0 * del1 * f330 * g300 * Q33 * aonv;
+            let del1 = del1 * f311 * g310 * Q31 * aonv;
+            let xlamo = (epoch.mo + epoch.nodeo + epoch.argpo - theta) % TAU;
+            let xfact = epoch.mdot + epoch.xpidot - RPTIM + dmdt + domdt + dnodt - epoch.no;
+            (Resonance::Synchronous { del1, del2, del3 }, xfact, xlamo)
+        } else if (8.26e-3..=9.24e-3).contains(&nm) && em >= 0.5 {
+            let terms = half_day_terms(epoch.ecco, epoch.eccsq, sinim, cosim, nm, aonv);
+            let xlamo = (epoch.mo + epoch.nodeo + epoch.nodeo - theta - theta) % TAU;
+            let xfact = epoch.mdot + dmdt + 2.0 * (epoch.nodedot + dnodt - RPTIM) - epoch.no;
+            (Resonance::HalfDay(Box::new(terms)), xfact, xlamo)
+        } else {
+            (Resonance::None, 0.0, 0.0)
+        };
+
+        DeepSpace {
+            se2: 2.0 * ss.s1 * ss.s6,
+            se3: 2.0 * ss.s1 * ss.s7,
+            si2: 2.0 * ss.s2 * ss.z12,
+            si3: 2.0 * ss.s2 * (ss.z13 - ss.z11),
+            sl2: -2.0 * ss.s3 * ss.z2,
+            sl3: -2.0 * ss.s3 * (ss.z3 - ss.z1),
+            sl4: -2.0 * ss.s3 * (-21.0 - 9.0 * emsq) * ZES,
+            sgh2: 2.0 * ss.s4 * ss.z32,
+            sgh3: 2.0 * ss.s4 * (ss.z33 - ss.z31),
+            sgh4: -18.0 * ss.s4 * ZES,
+            sh2: -2.0 * ss.s2 * ss.z22,
+            sh3: -2.0 * ss.s2 * (ss.z23 - ss.z21),
+            ee2: 2.0 * s.s1 * s.s6,
+            e3: 2.0 * s.s1 * s.s7,
+            xi2: 2.0 * s.s2 * s.z12,
+            xi3: 2.0 * s.s2 * (s.z13 - s.z11),
+            xl2: -2.0 * s.s3 * s.z2,
+            xl3: -2.0 * s.s3 * (s.z3 - s.z1),
+            xl4: -2.0 * s.s3 * (-21.0 - 9.0 * emsq) * ZEL,
+            xgh2: 2.0 * s.s4 * s.z32,
+            xgh3: 2.0 * s.s4 * (s.z33 - s.z31),
+            xgh4: -18.0 * s.s4 * ZEL,
+            xh2: -2.0 * s.s2 * s.z22,
+            xh3: -2.0 * s.s2 * (s.z23 - s.z21),
+            zmol,
+            zmos,
+            dedt,
+            didt,
+            dmdt,
+            dnodt,
+            domdt,
+            resonance,
+            xfact,
+            xlamo,
+            gsto: epoch.gsto,
+        }
+    }
+
+    /// Applies the deep-space secular effects and resonance integration
+    /// (`dspace`) at `t` minutes since epoch.
+    ///
+    /// The resonance integrator always restarts from epoch, so propagation
+    /// does not depend on previous calls.
+    pub(super) fn secular(&self, t: f64, no: f64, argpo: f64, argpdot: f64, m: &mut MeanElements) {
+        const FASX2: f64 = 0.131_309_08;
+        const FASX4: f64 = 2.884_319_8;
+        const FASX6: f64 = 0.374_480_87;
+        const G22: f64 = 5.768_639_6;
+        const G32: f64 = 0.952_408_98;
+        const G44: f64 = 1.801_499_8;
+        const G52: f64 = 1.050_833_0;
+        const G54: f64 = 4.410_889_8;
+        const STEPP: f64 = 720.0;
+        const STEPN: f64 = -720.0;
+        const STEP2: f64 = 259_200.0;
+
+        let theta = (self.gsto + t * RPTIM) % TAU;
+        m.em += self.dedt * t;
+        m.inclm += self.didt * t;
+        m.argpm += self.domdt * t;
+        m.nodem += self.dnodt * t;
+        m.mm += self.dmdt * t;
+
+        if let Resonance::None = self.resonance {
+            return;
+        }
+
+        let delt = if t > 0.0 { STEPP } else { STEPN };
+        let mut atime = 0.0;
+        let mut xni = no;
+        let mut xli = self.xlamo;
+
+        let (xndt, xldot, xnddt, ft) = loop {
+            let xldot = xni + self.xfact;
+            let (xndt, xnddt) = match &self.resonance {
+                Resonance::Synchronous { del1, del2, del3 } => {
+                    let xndt = del1 * (xli - FASX2).sin()
+                        + del2 * (2.0 * (xli - FASX4)).sin()
+                        + del3 * (3.0 * (xli - FASX6)).sin();
+                    let xnddt = del1 * (xli - FASX2).cos()
+                        + 2.0 * del2 * (2.0 * (xli - FASX4)).cos()
+                        + 3.0 * del3 * (3.0 * (xli - FASX6)).cos();
+                    (xndt, xnddt * xldot)
+                }
+                Resonance::HalfDay(d) => {
+                    let xomi = argpo + argpdot * atime;
+                    let x2omi = xomi + xomi;
+                    let x2li = xli + xli;
+                    let xndt = d.d2201 * (x2omi + xli - G22).sin()
+                        + d.d2211 * (xli - G22).sin()
+                        + d.d3210 * (xomi + xli - G32).sin()
+                        + d.d3222 * (-xomi + xli - G32).sin()
+                        + d.d4410 * (x2omi + x2li - G44).sin()
+                        + d.d4422 * (x2li - G44).sin()
+                        + d.d5220 * (xomi + xli - G52).sin()
+                        + d.d5232 * (-xomi + xli - G52).sin()
+                        + d.d5421 * (xomi + x2li - G54).sin()
+                        + d.d5433 * (-xomi + x2li - G54).sin();
+                    let xnddt = d.d2201 * (x2omi + xli - G22).cos()
+                        + d.d2211 * (xli - G22).cos()
+                        + d.d3210 * (xomi + xli - G32).cos()
+                        + d.d3222 * (-xomi + xli - G32).cos()
+                        + d.d5220 * (xomi + xli - G52).cos()
+                        + d.d5232 * (-xomi + xli - G52).cos()
+                        + 2.0
+                            * (d.d4410 * (x2omi + x2li - G44).cos()
+                                + d.d4422 * (x2li - G44).cos()
+                                + d.d5421 * (xomi + x2li - G54).cos()
+                                + d.d5433 * (-xomi + x2li - G54).cos());
+                    (xndt, xnddt * xldot)
+                }
+                Resonance::None => unreachable!(),
+            };
+
+            if (t - atime).abs() < STEPP {
+                break (xndt, xldot, xnddt, t - atime);
+            }
+
+            xli += xldot * delt + xndt * STEP2;
+            xni += xndt * delt + xnddt * STEP2;
+            atime += delt;
+        };
+
+        let nm = xni + xndt * ft + xnddt * ft * ft * 0.5;
+        let xl = xli + xldot * ft + xndt * ft * ft * 0.5;
+        m.mm = match self.resonance {
+            Resonance::Synchronous { .. } => xl - m.nodem - m.argpm + theta,
+            _ => xl - 2.0 * m.nodem + 2.0 * theta,
+        };
+        m.nm = nm;
+    }
+
+    /// Applies the lunar-solar periodic perturbations (`dpper`) at `t`
+    /// minutes since epoch.
+    pub(super) fn periodics(&self, t: f64, p: &mut PeriodicElements) {
+        let zm = self.zmos + ZNS * t;
+        let zf = zm + 2.0 * ZES * zm.sin();
+        let sinzf = zf.sin();
+        let f2 = 0.5 * sinzf * sinzf - 0.25;
+        let f3 = -0.5 * sinzf * zf.cos();
+        let ses = self.se2 * f2 + self.se3 * f3;
+        let sis = self.si2 * f2 + self.si3 * f3;
+        let sls = self.sl2 * f2 + self.sl3 * f3 + self.sl4 * sinzf;
+        let sghs = self.sgh2 * f2 + self.sgh3 * f3 + self.sgh4 * sinzf;
+        let shs = self.sh2 * f2 + self.sh3 * f3;
+
+        let zm = self.zmol + ZNL * t;
+        let zf = zm + 2.0 * ZEL * zm.sin();
+        let sinzf = zf.sin();
+        let f2 = 0.5 * sinzf * sinzf - 0.25;
+        let f3 = -0.5 * sinzf * zf.cos();
+        let sel = self.ee2 * f2 + self.e3 * f3;
+        let sil = self.xi2 * f2 + self.xi3 * f3;
+        let sll = self.xl2 * f2 + self.xl3 * f3 + self.xl4 * sinzf;
+        let sghl = self.xgh2 * f2 + self.xgh3 * f3 + self.xgh4 * sinzf;
+        let shll = self.xh2 * f2 + self.xh3 * f3;
+
+        let pe = ses + sel;
+        let pinc = sis + sil;
+        let pl = sls + sll;
+        let mut pgh = sghs + sghl;
+        let mut ph = shs + shll;
+
+        p.inclp += pinc;
+        p.ep += pe;
+        let sinip = p.inclp.sin();
+        let cosip = p.inclp.cos();
+
+        if p.inclp >= 0.2 {
+            ph /= sinip;
+            pgh -= cosip * ph;
+            p.argpp += pgh;
+            p.nodep += ph;
+            p.mp += pl;
+        } else {
+            // Lyddane modification for low inclinations
+            let sinop = p.nodep.sin();
+            let cosop = p.nodep.cos();
+            let alfdp = sinip * sinop + ph * cosop + pinc * cosip * sinop;
+            let betdp = sinip * cosop - ph * sinop + pinc * cosip * cosop;
+            p.nodep %= TAU;
+            let xls = p.mp + p.argpp + cosip * p.nodep + pl + pgh - pinc * p.nodep * sinip;
+            let xnoh = p.nodep;
+            p.nodep = alfdp.atan2(betdp);
+            if (xnoh - p.nodep).abs() > PI {
+                if p.nodep < xnoh {
+                    p.nodep += TAU;
+                } else {
+                    p.nodep -= TAU;
+                }
+            }
+            p.mp += pl;
+            p.argpp = xls - p.mp - cosip * p.nodep;
+        }
+    }
+}
+
+/// Orientation of the Sun or Moon used by `dscom`.
+struct ThirdBodyGeometry {
+    zcosg: f64,
+    zsing: f64,
+    zcosi: f64,
+    zsini: f64,
+    zcosh: f64,
+    zsinh: f64,
+    cc: f64,
+}
+
+/// Intermediate `dscom` terms for a single perturbing body.
+struct ThirdBody {
+    s1: f64,
+    s2: f64,
+    s3: f64,
+    s4: f64,
+    s5: f64,
+    s6: f64,
+    s7: f64,
+    z1: f64,
+    z2: f64,
+    z3: f64,
+    z11: f64,
+    z12: f64,
+    z13: f64,
+    z21: f64,
+    z22: f64,
+    z23: f64,
+    z31: f64,
+    z32: f64,
+    z33: f64,
+}
+
+impl ThirdBody {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        g: ThirdBodyGeometry,
+        sinim: f64,
+        cosim: f64,
+        sinomm: f64,
+        cosomm: f64,
+        em: f64,
+        emsq: f64,
+        betasq: f64,
+        rtemsq: f64,
+        nm: f64,
+    ) -> Self {
+        let a1 = g.zcosg * g.zcosh + g.zsing * g.zcosi * g.zsinh;
+        let a3 = -g.zsing * g.zcosh + g.zcosg * g.zcosi * g.zsinh;
+        let a7 = -g.zcosg * g.zsinh + g.zsing * g.zcosi * g.zcosh;
+        let a8 = g.zsing * g.zsini;
+        let a9 = g.zsing * g.zsinh + g.zcosg * g.zcosi * g.zcosh;
+        let a10 = g.zcosg * g.zsini;
+        let a2 = cosim * a7 + sinim * a8;
+        let a4 = cosim * a9 + sinim * a10;
+        let a5 = -sinim * a7 + cosim * a8;
+        let a6 = -sinim * a9 + cosim * a10;
+
+        let x1 = a1 * cosomm + a2 * sinomm;
+        let x2 = a3 * cosomm + a4 * sinomm;
+        let x3 = -a1 * sinomm + a2 * cosomm;
+        let x4 = -a3 * sinomm + a4 * cosomm;
+        let x5 = a5 * sinomm;
+        let x6 = a6 * sinomm;
+        let x7 = a5 * cosomm;
+        let x8 = a6 * cosomm;
+
+        let z31 = 12.0 * x1 * x1 - 3.0 * x3 * x3;
+        let z32 = 24.0 * x1 * x2 - 6.0 * x3 * x4;
+        let z33 = 12.0 * x2 * x2 - 3.0 * x4 * x4;
+        let z1 = 3.0 * (a1 * a1 + a2 * a2) + z31 * emsq;
+        let z2 = 6.0 * (a1 * a3 + a2 * a4) + z32 * emsq;
+        let z3 = 3.0 * (a3 * a3 + a4 * a4) + z33 * emsq;
+        let z11 = -6.0 * a1 * a5 + emsq * (-24.0 * x1 * x7 - 6.0 * x3 * x5);
+        let z12 = -6.0 * (a1 * a6 + a3 * a5)
+            + emsq * (-24.0 * (x2 * x7 + x1 * x8) - 6.0 * (x3 * x6 + x4 * x5));
+        let z13 = -6.0 * a3 * a6 + emsq * (-24.0 * x2 * x8 - 6.0 * x4 * x6);
+        let z21 = 6.0 * a2 * a5 + emsq * (24.0 * x1 * x5 - 6.0 * x3 * x7);
+        let z22 = 6.0 * (a4 * a5 + a2 * a6)
+            + emsq * (24.0 * (x2 * x5 + x1 * x6) - 6.0 * (x4 * x7 + x3 * x8));
+        let z23 = 6.0 * a4 * a6 + emsq * (24.0 * x2 * x6 - 6.0 * x4 * x8);
+        let z1 = z1 + z1 + betasq * z31;
+        let z2 = z2 + z2 + betasq * z32;
+        let z3 = z3 + z3 + betasq * z33;
+
+        let s3 = g.cc / nm;
+        let s2 = -0.5 * s3 / rtemsq;
+        let s4 = s3 * rtemsq;
+        let s1 = -15.0 * em * s4;
+        let s5 = x1 * x3 + x2 * x4;
+        let s6 = x2 * x3 + x1 * x4;
+        let s7 = x2 * x4 - x1 * x3;
+
+        ThirdBody {
+            s1,
+            s2,
+            s3,
+            s4,
+            s5,
+            s6,
+            s7,
+            z1,
+            z2,
+            z3,
+            z11,
+            z12,
+            z13,
+            z21,
+            z22,
+            z23,
+            z31,
+            z32,
+            z33,
+        }
+    }
+}
+
+/// Geopotential resonance coefficients for half-day orbits.
+fn half_day_terms(em: f64, emsq: f64, sinim: f64, cosim: f64, nm: f64, aonv: f64) -> HalfDayTerms {
+    const ROOT22: f64 = 1.789_167_9e-6;
+    const ROOT32: f64 = 3.739_379_2e-7;
+    const ROOT44: f64 = 7.363_695_3e-9;
+    const ROOT52: f64 = 1.142_863_9e-7;
+    const ROOT54: f64 = 2.176_580_3e-9;
+
+    let cosisq = cosim * cosim;
+    let eoc = em * emsq;
+    let g201 = -0.306 - (em - 0.64) * 0.440;
+
+    let (g211, g310, g322, g410, g422, g520) = if em <= 0.65 {
+        (
+            3.616 - 13.2470 * em + 16.2900 * emsq,
+            -19.302 + 117.3900 * em - 228.4190 * emsq + 156.5910 * eoc,
+            -18.9068 + 109.7927 * em - 214.6334 * emsq + 146.5816 * eoc,
+            -41.122 + 242.6940 * em - 471.0940 * emsq + 313.9530 * eoc,
+            -146.407 + 841.8800 * em - 1629.014 * emsq + 1083.4350 * eoc,
+            -532.114 + 3017.977 * em - 5740.032 * emsq + 3708.2760 * eoc,
+        )
+    } else {
+        (
+            -72.099 + 331.819 * em - 508.738 * emsq + 266.724 * eoc,
+            -346.844 + 1582.851 * em - 2415.925 * emsq + 1246.113 * eoc,
+            -342.585 + 1554.908 * em - 2366.899 * emsq + 1215.972 * eoc,
+            -1052.797 + 4758.686 * em - 7193.992 * emsq + 3651.957 * eoc,
+            -3581.690 + 16178.110 * em - 24462.770 * emsq + 12422.520 * eoc,
+            if em > 0.715 {
+                -5149.66 + 29936.92 * em - 54087.36 * emsq + 31324.56 * eoc
+            } else {
+                1464.74 - 4664.75 * em + 3763.64 * emsq
+            },
+        )
+    };
+
+    let (g533, g521, g532) = if em < 0.7 {
+        (
+            -919.22770 + 4988.6100 * em - 9064.7700 * emsq + 5542.21 * eoc,
+            -822.71072 + 4568.6173 * em - 8491.4146 * emsq + 5337.524 * eoc,
+            -853.66600 + 4690.2500 * em - 8624.7700 * emsq + 5341.4 * eoc,
+        )
+    } else {
+        (
+            -37995.780 + 161616.52 * em - 229838.20 * emsq + 109377.94 * eoc,
+            -51752.104 + 218913.95 * em - 309468.16 * emsq + 146349.42 * eoc,
+            -40023.880 + 170470.89 * em - 242699.48 * emsq + 115605.82 * eoc,
+        )
+    };
+
+    let sini2 = sinim * sinim;
+    let f220 = 0.75 * (1.0 + 2.0 * cosim + cosisq);
+    let f221 = 1.5 * sini2;
+    let f321 = 1.875 * sinim * (1.0 - 2.0 * cosim - 3.0 * cosisq);
+    let f322 = -1.875 * sinim * (1.0 + 2.0 * cosim - 3.0 * cosisq);
+    let f441 = 35.0 * sini2 * f220;
+    let f442 = 39.3750 * sini2 * sini2;
+    let f522 = 9.84375
+        * sinim
+        * (sini2 * (1.0 - 2.0 * cosim - 5.0 * cosisq)
+            + 0.333_333_33 * (-2.0 + 4.0 * cosim + 6.0 * cosisq));
+    let f523 = sinim
+        * (4.921_875_12 * sini2 * (-2.0 - 4.0 * cosim + 10.0 * cosisq)
+            + 6.562_500_12 * (1.0 + 2.0 * cosim - 3.0 * cosisq));
+    let f542 =
+        29.53125 * sinim * (2.0 - 8.0 * cosim + cosisq * (-12.0 + 8.0 * cosim + 10.0 * cosisq));
+    let f543 =
+        29.53125 * sinim * (-2.0 - 8.0 * cosim + cosisq * (12.0 + 8.0 * cosim - 10.0 * cosisq));
+
+    let xno2 = nm * nm;
+    let ainv2 = aonv * aonv;
+    let temp1 = 3.0 * xno2 * ainv2;
+    let temp = temp1 * ROOT22;
+    let d2201 = temp * f220 * g201;
+    let d2211 = temp * f221 * g211;
+    let temp1 = temp1 * aonv;
+    let temp = temp1 * ROOT32;
+    let d3210 = temp * f321 * g310;
+    let d3222 = temp * f322 * g322;
+    let temp1 = temp1 * aonv;
+    let temp = 2.0 * temp1 * ROOT44;
+    let d4410 = temp * f441 * g410;
+    let d4422 = temp * f442 * g422;
+    let temp1 = temp1 * aonv;
+    let temp = temp1 * ROOT52;
+    let d5220 = temp * f522 * g520;
+    let d5232 = temp * f523 * g532;
+    let temp = 2.0 * temp1 * ROOT54;
+    let d5421 = temp * f542 * g521;
+    let d5433 = temp * f543 * g533;
+
+    HalfDayTerms {
+        d2201,
+        d2211,
+        d3210,
+        d3222,
+        d4410,
+        d4422,
+        d5220,
+        d5232,
+        d5421,
+        d5433,
+    }
+}
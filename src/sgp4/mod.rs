@@ -0,0 +1,718 @@
+//! # SGP4/SDP4 Propagator
+//!
+//! Pure-Rust implementation of the Simplified General Perturbations model
+//! used with Two-Line Element sets, following the revised algorithm of
+//! Vallado et al., *"Revisiting Spacetrack Report #3"* (AIAA 2006-6753),
+//! with WGS-72 constants and the "improved" operation mode.
+//!
+//! Orbits with a period of 225 minutes or more automatically use the
+//! deep-space (SDP4) lunar-solar and resonance terms.
+//!
+//! States are returned in the **TEME** (True Equator, Mean Equinox) frame,
+//! the native output frame of SGP4.
+//!
+//! ## Verification
+//!
+//! States match the reference output of the SGP4-VER test set (`tcppver.out`)
+//! within 1 mm and 1 µm/s: over a day for the near-Earth case, and at epoch
+//! for the deep-space ones. Past epoch, the deep-space cases are also pinned
+//! to states recorded from this implementation, so that changes to the
+//! resonance integration or the time-dependent lunar-solar terms are caught.
+//!
+//! ```
+//! use rustar_types::jobs::TleData;
+//!
+//! // (TLE, [(minutes since epoch, [x, y, z, vx, vy, vz])]) in km and km/s
+//! let reference = [
+//!     // Near-Earth, eccentric
+//!     ("00005
+//! 1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+//! 2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667", vec![
+//!         (0.0, [7022.46529266, -1400.08296755, 0.03995155, 1.893841015, 6.405893759, 4.534807250]),
+//!         (360.0, [-7154.03120202, -3783.17682504, -3536.19412294, 4.741887409, -4.151817765, -2.093935425]),
+//!         (720.0, [-7134.59340119, 6531.68641334, 3260.27186483, -4.113793027, -2.911922039, -2.557327851]),
+//!         (1080.0, [5568.53901181, 4492.06992591, 3863.87641983, -4.209106476, 5.159719888, 2.744852980]),
+//!         (1440.0, [-938.55923943, -6268.18748831, -4294.02924751, 7.536105209, -0.427127707, 0.989878080]),
+//!     ]),
+//!     // 12 h resonant Molniya
+//!     ("08195
+//! 1 08195U 75081A   06176.33215444  .00000099  00000-0  11873-3 0   813
+//! 2 08195  64.1586 279.0717 6877146 264.7651  20.2257  2.00491383225656", vec![
+//!         (0.0, [2349.89483350, -14785.93811562, 0.02119378, 2.721488096, -3.256811655, 4.498416672]),
+//!     ]),
+//!     ("09880
+//! 1 09880U 77021A   06176.56157475  .00000421  00000-0  10000-3 0  9814
+//! 2 09880  64.5968 349.3786 7069051 270.0229  16.3320  2.00813614112380", vec![
+//!         (0.0, [13020.06750784, -2449.07193500, 1.15896030, 4.247363935, 1.597178501, 4.956708611]),
+//!     ]),
+//!     // 24 h synchronous
+//!     ("28626
+//! 1 28626U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2190
+//! 2 28626   0.0019 286.9433 0000335  13.7918  55.6504  1.00270176  4891", vec![
+//!         (0.0, [42080.71852213, -2646.86387436, 0.81851294, 0.193105177, 3.068688251, 0.000438449]),
+//!     ]),
+//!     // Low-inclination deep space
+//!     ("24208
+//! 1 24208U 96044A   06177.04061740 -.00000094  00000-0  10000-3 0  1600
+//! 2 24208   3.8536  80.0121 0026640 311.0977  48.3000  1.00778054 36119", vec![
+//!         (0.0, [7534.10987189, 41266.39266843, -0.10801028, -3.027168008, 0.558848996, 0.207982755]),
+//!     ]),
+//!     ("25954
+//! 1 25954U 99060A   04039.68057285 -.00000108  00000-0  00000-0 0  6847
+//! 2 25954   0.0004 243.8136 0001765  15.5294  22.7134  1.00271289 15615", vec![
+//!         (0.0, [8827.15660472, -41223.00971237, 3.63482963, 3.007087319, 0.643701323, 0.000941663]),
+//!     ]),
+//! ];
+//!
+//! // Recorded from this implementation, not from `tcppver.out`
+//! let recorded = [
+//!     ("08195", 2880.0, [3417.20931586, -16038.79510665, 1894.74934058, 2.585515864, -2.596818146, 4.456882556]),
+//!     ("09880", 2880.0, [15500.53445068, -1332.90981042, 3419.72315308, 2.960917974, 1.758331634, 4.813698638]),
+//!     ("28626", 1440.0, [42119.96263499, -1925.77567263, -0.19827433, 0.140521206, 3.071541613, 0.000179561]),
+//!     ("24208", 1440.0, [5501.08137100, 41590.27784405, 138.32522930, -3.050691874, 0.409203052, 0.207958133]),
+//!     ("25954", 1440.0, [9533.27750818, -41065.52390214, 3.30756482, 2.995596171, 0.695200236, 0.000938525]),
+//! ];
+//!
+//! for (text, mut states) in reference {
+//!     let tle = TleData::try_from(text.to_string()).unwrap();
+//!     let sgp4 = tle.propagator().unwrap();
+//!     states.extend(recorded.iter().filter(|(id, ..)| *id == tle.tle0).map(|(_, t, s)| (*t, *s)));
+//!
+//!     for (tsince, expected) in states {
+//!         let state = sgp4.propagate_minutes(tsince).unwrap();
+//!         for axis in 0..3 {
+//!             assert!((state.position[axis] - expected[axis]).abs() < 1e-6, "{} at {tsince}", tle.tle0);
+//!             assert!((state.velocity[axis] - expected[axis + 3]).abs() < 1e-9, "{} at {tsince}", tle.tle0);
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::f64::consts::{PI, TAU};
+use std::fmt;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::jobs::{ElementsParseError, Omm, OrbitData, OrbitalElements, TleData};
+
+mod deep_space;
+
+use deep_space::{DeepSpace, MeanElements, PeriodicElements};
+
+/// Earth equatorial radius (WGS-72), in km.
+pub(crate) const EARTH_RADIUS: f64 = 6378.135;
+/// Square root of the WGS-72 gravitational parameter (398600.8 km³/s²),
+/// in Earth radii^1.5 per minute.
+const XKE: f64 = 0.074_366_916_133_173_42;
+const J2: f64 = 0.001_082_616;
+const J3: f64 = -0.000_002_538_81;
+const J4: f64 = -0.000_001_655_97;
+const J3OJ2: f64 = J3 / J2;
+const X2O3: f64 = 2.0 / 3.0;
+/// Minutes per day divided by 2π, to convert rev/day into rad/min.
+const XPDOTP: f64 = 1440.0 / TAU;
+
+/// # State Vector
+///
+/// Position and velocity of a satellite in the TEME frame.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    /// Position, in kilometers.
+    pub position: [f64; 3],
+    /// Velocity, in kilometers per second.
+    pub velocity: [f64; 3],
+}
+
+/// Error type for SGP4 initialization and propagation failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sgp4Error {
+    /// The TLE fields could not be parsed into orbital elements
+    InvalidElements(ElementsParseError),
+    /// Mean eccentricity is outside `[0, 1)`
+    EccentricityOutOfRange,
+    /// Mean motion became negative
+    NegativeMeanMotion,
+    /// Eccentricity after lunar-solar periodics is outside `[0, 1]`
+    PerturbedEccentricityOutOfRange,
+    /// Semi-latus rectum became negative
+    NegativeSemiLatusRectum,
+    /// The orbit has decayed below the Earth's surface
+    Decayed,
+}
+
+impl fmt::Display for Sgp4Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Sgp4Error::*;
+        match self {
+            InvalidElements(error) => write!(f, "invalid orbital elements: {error}"),
+            EccentricityOutOfRange => f.write_str("mean eccentricity is outside [0, 1)"),
+            NegativeMeanMotion => f.write_str("mean motion became negative"),
+            PerturbedEccentricityOutOfRange => {
+                f.write_str("perturbed eccentricity is outside [0, 1]")
+            }
+            NegativeSemiLatusRectum => f.write_str("semi-latus rectum became negative"),
+            Decayed => f.write_str("the orbit has decayed"),
+        }
+    }
+}
+
+impl std::error::Error for Sgp4Error {}
+
+/// # SGP4 Propagator
+///
+/// Initialized once from a set of [`OrbitalElements`] and then evaluated at
+/// any time. Propagation does not mutate the propagator, so a single
+/// instance can be shared across threads.
+///
+/// ## Example
+/// ```
+/// use rustar_types::jobs::TleData;
+/// use rustar_types::sgp4::Sgp4;
+///
+/// // Vallado verification case 00005
+/// let tle = TleData::try_from("00005
+/// 1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+/// 2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667".to_string()).unwrap();
+///
+/// let sgp4 = Sgp4::new(&tle.elements().unwrap()).unwrap();
+/// let state = sgp4.propagate_minutes(360.0).unwrap();
+///
+/// let expected = [-7154.03120202, -3783.17682504, -3536.19412294];
+/// for (actual, expected) in state.position.iter().zip(expected) {
+///     assert!((actual - expected).abs() < 1e-6);
+/// }
+///
+/// // Vallado verification case 28626, a geosynchronous deep-space orbit
+/// let tle = TleData::try_from("28626
+/// 1 28626U 05008A   06176.46683397 -.00000205  00000-0  10000-3 0  2190
+/// 2 28626   0.0019 286.9433 0000335  13.7918  55.6504  1.00270176  4891".to_string()).unwrap();
+///
+/// let state = tle.propagator().unwrap().propagate_minutes(0.0).unwrap();
+///
+/// let expected = [0.193105177, 3.068688251, 0.000438449];
+/// for (actual, expected) in state.velocity.iter().zip(expected) {
+///     assert!((actual - expected).abs() < 1e-9);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Sgp4 {
+    epoch: DateTime<Utc>,
+
+    // Mean elements at epoch
+    bstar: f64,
+    ecco: f64,
+    argpo: f64,
+    inclo: f64,
+    mo: f64,
+    no_unkozai: f64,
+    nodeo: f64,
+
+    // Near-earth coefficients
+    aycof: f64,
+    con41: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    eta: f64,
+    argpdot: f64,
+    omgcof: f64,
+    sinmao: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    mdot: f64,
+    nodedot: f64,
+    xlcof: f64,
+    xmcof: f64,
+    nodecf: f64,
+    is_simple: bool,
+
+    deep_space: Option<Box<DeepSpace>>,
+}
+
+impl Sgp4 {
+    /// Initializes the propagator from a set of mean elements (`sgp4init`).
+    pub fn new(elements: &OrbitalElements) -> Result<Self, Sgp4Error> {
+        let ecco = elements.eccentricity;
+        if !(0.0..1.0).contains(&ecco) {
+            return Err(Sgp4Error::EccentricityOutOfRange);
+        }
+
+        let no_kozai = elements.mean_motion / XPDOTP;
+        if no_kozai <= 0.0 {
+            return Err(Sgp4Error::NegativeMeanMotion);
+        }
+
+        let bstar = elements.drag_term;
+        let inclo = elements.inclination.to_radians();
+        let nodeo = elements.right_ascension.to_radians();
+        let argpo = elements.argument_of_perigee.to_radians();
+        let mo = elements.mean_anomaly.to_radians();
+        let epoch = days_since_1950(elements.epoch);
+
+        // initl: recover the original (un-Kozai'd) mean motion
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let cosio = inclo.cos();
+        let cosio2 = cosio * cosio;
+
+        let ak = (XKE / no_kozai).powf(X2O3);
+        let d1 = 0.75 * J2 * (3.0 * cosio2 - 1.0) / (rteosq * omeosq);
+        let del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        let del = d1 / (adel * adel);
+        let no_unkozai = no_kozai / (1.0 + del);
+
+        let ao = (XKE / no_unkozai).powf(X2O3);
+        let sinio = inclo.sin();
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+        let gsto = gstime(epoch + 2_433_281.5);
+
+        // sgp4init
+        let ss = 78.0 / EARTH_RADIUS + 1.0;
+        let qzms2t = ((120.0 - 78.0) / EARTH_RADIUS).powi(4);
+
+        let mut is_simple = rp < 220.0 / EARTH_RADIUS + 1.0;
+
+        // For perigees below 156 km, the values of S and QOMS2T are altered
+        let mut sfour = ss;
+        let mut qzms24 = qzms2t;
+        let perige = (rp - 1.0) * EARTH_RADIUS;
+        if perige < 156.0 {
+            sfour = if perige < 98.0 { 20.0 } else { perige - 78.0 };
+            qzms24 = ((120.0 - sfour) / EARTH_RADIUS).powi(4);
+            sfour = sfour / EARTH_RADIUS + 1.0;
+        }
+
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no_unkozai
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = bstar * cc2;
+        let cc3 = if ecco > 1.0e-4 {
+            -2.0 * coef * tsi * J3OJ2 * no_unkozai * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no_unkozai
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no_unkozai;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no_unkozai;
+        let mdot = no_unkozai
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1
+            + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+        let xpidot = argpdot + nodedot;
+        let omgcof = bstar * cc3 * argpo.cos();
+        let xmcof = if ecco > 1.0e-4 {
+            -X2O3 * coef * bstar / eeta
+        } else {
+            0.0
+        };
+        let nodecf = 3.5 * omeosq * xhdot1 * cc1;
+        let t2cof = 1.5 * cc1;
+        let xlcof = long_period_xlcof(sinio, cosio);
+        let aycof = -0.5 * J3OJ2 * sinio;
+        let delmo = (1.0 + eta * mo.cos()).powi(3);
+        let sinmao = mo.sin();
+        let x7thm1 = 7.0 * cosio2 - 1.0;
+
+        let deep_space = if TAU / no_unkozai >= 225.0 {
+            is_simple = true;
+            Some(Box::new(DeepSpace::new(&deep_space::Epoch {
+                epoch,
+                ecco,
+                eccsq,
+                argpo,
+                inclo,
+                nodeo,
+                mo,
+                no: no_unkozai,
+                mdot,
+                nodedot,
+                xpidot,
+                gsto,
+            })))
+        } else {
+            None
+        };
+
+        let (mut d2, mut d3, mut d4, mut t3cof, mut t4cof, mut t5cof) =
+            (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        if !is_simple {
+            let cc1sq = cc1 * cc1;
+            d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            d3 = (17.0 * ao + sfour) * temp;
+            d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            t3cof = d2 + 2.0 * cc1sq;
+            t4cof = 0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq));
+            t5cof = 0.2
+                * (3.0 * d4 + 12.0 * cc1 * d3 + 6.0 * d2 * d2 + 15.0 * cc1sq * (2.0 * d2 + cc1sq));
+        }
+
+        let sgp4 = Sgp4 {
+            epoch: elements.epoch,
+            bstar,
+            ecco,
+            argpo,
+            inclo,
+            mo,
+            no_unkozai,
+            nodeo,
+            aycof,
+            con41,
+            cc1,
+            cc4,
+            cc5,
+            d2,
+            d3,
+            d4,
+            delmo,
+            eta,
+            argpdot,
+            omgcof,
+            sinmao,
+            t2cof,
+            t3cof,
+            t4cof,
+            t5cof,
+            x1mth2,
+            x7thm1,
+            mdot,
+            nodedot,
+            xlcof,
+            xmcof,
+            nodecf,
+            is_simple,
+            deep_space,
+        };
+
+        // Propagating to epoch surfaces elements that can't be evaluated
+        sgp4.propagate_minutes(0.0)?;
+
+        Ok(sgp4)
+    }
+
+    /// Epoch of the elements the propagator was initialized with.
+    pub fn epoch(&self) -> DateTime<Utc> {
+        self.epoch
+    }
+
+    /// Returns the TEME state of the satellite at `time`.
+    pub fn propagate(&self, time: DateTime<Utc>) -> Result<StateVector, Sgp4Error> {
+        self.propagate_minutes(minutes_between(self.epoch, time))
+    }
+
+    /// Returns the TEME state of the satellite `tsince` minutes after epoch.
+    pub fn propagate_minutes(&self, tsince: f64) -> Result<StateVector, Sgp4Error> {
+        let t = tsince;
+
+        // Secular gravity and atmospheric drag
+        let xmdf = self.mo + self.mdot * t;
+        let argpdf = self.argpo + self.argpdot * t;
+        let nodedf = self.nodeo + self.nodedot * t;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let t2 = t * t;
+        let mut nodem = nodedf + self.nodecf * t2;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+
+        if !self.is_simple {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            let temp = delomg + delm;
+            mm = xmdf + temp;
+            argpm = argpdf - temp;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa = tempa - self.d2 * t2 - self.d3 * t3 - self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+
+        let mut nm = self.no_unkozai;
+        let mut em = self.ecco;
+        let mut inclm = self.inclo;
+
+        if let Some(deep_space) = &self.deep_space {
+            let mut mean = MeanElements {
+                em,
+                argpm,
+                inclm,
+                mm,
+                nodem,
+                nm,
+            };
+            deep_space.secular(t, self.no_unkozai, self.argpo, self.argpdot, &mut mean);
+            MeanElements {
+                em,
+                argpm,
+                inclm,
+                mm,
+                nodem,
+                nm,
+            } = mean;
+        }
+
+        if nm <= 0.0 {
+            return Err(Sgp4Error::NegativeMeanMotion);
+        }
+
+        let am = (XKE / nm).powf(X2O3) * tempa * tempa;
+        nm = XKE / am.powf(1.5);
+        em -= tempe;
+
+        if !(-0.001..1.0).contains(&em) {
+            return Err(Sgp4Error::EccentricityOutOfRange);
+        }
+        em = em.max(1.0e-6);
+        mm += self.no_unkozai * templ;
+        let xlm = mm + argpm + nodem;
+        nodem %= TAU;
+        argpm %= TAU;
+        let xlm = xlm % TAU;
+        mm = (xlm - argpm - nodem) % TAU;
+
+        // Lunar-solar periodics
+        let mut periodic = PeriodicElements {
+            ep: em,
+            inclp: inclm,
+            nodep: nodem,
+            argpp: argpm,
+            mp: mm,
+        };
+        let (mut aycof, mut xlcof) = (self.aycof, self.xlcof);
+        let (mut con41, mut x1mth2, mut x7thm1) = (self.con41, self.x1mth2, self.x7thm1);
+
+        if let Some(deep_space) = &self.deep_space {
+            deep_space.periodics(t, &mut periodic);
+            if periodic.inclp < 0.0 {
+                periodic.inclp = -periodic.inclp;
+                periodic.nodep += PI;
+                periodic.argpp -= PI;
+            }
+            if !(0.0..=1.0).contains(&periodic.ep) {
+                return Err(Sgp4Error::PerturbedEccentricityOutOfRange);
+            }
+
+            let sinip = periodic.inclp.sin();
+            let cosip = periodic.inclp.cos();
+            aycof = -0.5 * J3OJ2 * sinip;
+            xlcof = long_period_xlcof(sinip, cosip);
+
+            let cosisq = cosip * cosip;
+            con41 = 3.0 * cosisq - 1.0;
+            x1mth2 = 1.0 - cosisq;
+            x7thm1 = 7.0 * cosisq - 1.0;
+        }
+
+        let PeriodicElements {
+            ep,
+            inclp: xincp,
+            nodep,
+            argpp,
+            mp,
+        } = periodic;
+        let sinip = xincp.sin();
+        let cosip = xincp.cos();
+
+        // Long period periodics
+        let axnl = ep * argpp.cos();
+        let temp = 1.0 / (am * (1.0 - ep * ep));
+        let aynl = ep * argpp.sin() + temp * aycof;
+        let xl = mp + argpp + nodep + temp * xlcof * axnl;
+
+        // Solve Kepler's equation
+        let u = (xl - nodep) % TAU;
+        let mut eo1 = u;
+        let mut tem5: f64 = 9999.9;
+        let mut sineo1 = 0.0;
+        let mut coseo1 = 0.0;
+        let mut ktr = 1;
+        while tem5.abs() >= 1.0e-12 && ktr <= 10 {
+            sineo1 = eo1.sin();
+            coseo1 = eo1.cos();
+            tem5 = 1.0 - coseo1 * axnl - sineo1 * aynl;
+            tem5 = (u - aynl * coseo1 + axnl * sineo1 - eo1) / tem5;
+            tem5 = tem5.clamp(-0.95, 0.95);
+            eo1 += tem5;
+            ktr += 1;
+        }
+
+        // Short period preliminary quantities
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err(Sgp4Error::NegativeSemiLatusRectum);
+        }
+
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * J2 * temp;
+        let temp2 = temp1 * temp;
+
+        // Short period periodics
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * con41) + 0.5 * temp1 * x1mth2 * cos2u;
+        let su = su - 0.25 * temp2 * x7thm1 * sin2u;
+        let xnode = nodep + 1.5 * temp2 * cosip * sin2u;
+        let xinc = xincp + 1.5 * temp2 * cosip * sinip * cos2u;
+        let mvt = rdotl - nm * temp1 * x1mth2 * sin2u / XKE;
+        let rvdot = rvdotl + nm * temp1 * (x1mth2 * cos2u + 1.5 * con41) / XKE;
+
+        // Orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let ux = xmx * sinsu + cnod * cossu;
+        let uy = xmy * sinsu + snod * cossu;
+        let uz = sini * sinsu;
+        let vx = xmx * cossu - cnod * sinsu;
+        let vy = xmy * cossu - snod * sinsu;
+        let vz = sini * cossu;
+
+        if mrt < 1.0 {
+            return Err(Sgp4Error::Decayed);
+        }
+
+        let vkmpersec = EARTH_RADIUS * XKE / 60.0;
+        Ok(StateVector {
+            position: [
+                mrt * ux * EARTH_RADIUS,
+                mrt * uy * EARTH_RADIUS,
+                mrt * uz * EARTH_RADIUS,
+            ],
+            velocity: [
+                (mvt * ux + rvdot * vx) * vkmpersec,
+                (mvt * uy + rvdot * vy) * vkmpersec,
+                (mvt * uz + rvdot * vz) * vkmpersec,
+            ],
+        })
+    }
+}
+
+impl TryFrom<&OrbitalElements> for Sgp4 {
+    type Error = Sgp4Error;
+
+    fn try_from(elements: &OrbitalElements) -> Result<Self, Self::Error> {
+        Sgp4::new(elements)
+    }
+}
+
+impl TleData {
+    /// Parses the TLE set and initializes an [`Sgp4`] propagator for it.
+    pub fn propagator(&self) -> Result<Sgp4, Sgp4Error> {
+        let elements = self.elements().map_err(Sgp4Error::InvalidElements)?;
+        Sgp4::new(&elements)
+    }
+}
+
+impl Omm {
+    /// Converts the OMM and initializes an [`Sgp4`] propagator for it.
+    pub fn propagator(&self) -> Result<Sgp4, Sgp4Error> {
+        let elements = self.elements().map_err(Sgp4Error::InvalidElements)?;
+        Sgp4::new(&elements)
+    }
+}
+
+impl OrbitData {
+    /// Initializes an [`Sgp4`] propagator for the TLE set or OMM.
+    pub fn propagator(&self) -> Result<Sgp4, Sgp4Error> {
+        let elements = self.elements().map_err(Sgp4Error::InvalidElements)?;
+        Sgp4::new(&elements)
+    }
+}
+
+/// Long-period periodic coefficient, guarding against division by zero
+/// for retrograde equatorial orbits.
+fn long_period_xlcof(sinio: f64, cosio: f64) -> f64 {
+    const TEMP4: f64 = 1.5e-12;
+
+    let divisor = if (cosio + 1.0).abs() > TEMP4 {
+        1.0 + cosio
+    } else {
+        TEMP4
+    };
+    -0.25 * J3OJ2 * sinio * (3.0 + 5.0 * cosio) / divisor
+}
+
+/// Greenwich mean sidereal time (IAU-82), in radians, for a UT1 Julian date.
+pub(crate) fn gstime(jdut1: f64) -> f64 {
+    let tut1 = (jdut1 - 2_451_545.0) / 36525.0;
+    let seconds = -6.2e-6 * tut1 * tut1 * tut1
+        + 0.093_104 * tut1 * tut1
+        + (876_600.0 * 3600.0 + 8_640_184.812_866) * tut1
+        + 67_310.548_41;
+    (seconds.to_radians() / 240.0).rem_euclid(TAU)
+}
+
+/// Days elapsed since 1949 December 31 00:00 UT, the SGP4 time origin.
+fn days_since_1950(time: DateTime<Utc>) -> f64 {
+    let origin = Utc.with_ymd_and_hms(1949, 12, 31, 0, 0, 0).unwrap();
+    minutes_between(origin, time) / 1440.0
+}
+
+/// Minutes elapsed from `from` to `to`, with microsecond resolution.
+pub(crate) fn minutes_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    let elapsed = to - from;
+    match elapsed.num_microseconds() {
+        Some(microseconds) => microseconds as f64 / 60e6,
+        None => elapsed.num_milliseconds() as f64 / 60e3,
+    }
+}
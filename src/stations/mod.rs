@@ -0,0 +1,411 @@
+//! # Ground Stations
+//!
+//! Description of a ground station's location and capabilities, shared by
+//! the backend (to decide which jobs a station can run) and the stations
+//! themselves.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::frames::{Geodetic, Topocentric};
+use crate::mqtt::topics::GroundStationId;
+use crate::tracking::RotatorMode;
+
+/// Error type for inconsistent ground station descriptions
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroundStationError {
+    /// The latitude is outside `[-90, 90]` or the longitude outside
+    /// `[-180, 180]`
+    InvalidLocation,
+    /// An elevation mask value is outside `[-90, 90]`
+    InvalidElevationMask,
+    /// The horizon profile azimuths are not increasing within `[0, 360)`
+    InvalidHorizonProfile,
+    /// A frequency band is empty or has non-positive bounds
+    InvalidFrequencyBand { index: usize },
+    /// The rotator ranges are empty or its slew rates are not positive
+    InvalidRotatorLimits,
+}
+
+impl fmt::Display for GroundStationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use GroundStationError::*;
+        match self {
+            InvalidLocation => f.write_str("latitude or longitude is out of range"),
+            InvalidElevationMask => f.write_str("elevation mask is outside [-90, 90]"),
+            InvalidHorizonProfile => {
+                f.write_str("horizon profile azimuths are not increasing within [0, 360)")
+            }
+            InvalidFrequencyBand { index } => {
+                write!(f, "frequency band {index} is empty or not positive")
+            }
+            InvalidRotatorLimits => {
+                f.write_str("rotator ranges are empty or slew rates are not positive")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GroundStationError {}
+
+/// # Ground Station
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "id": "gs-buenos-aires",
+///   "name": "Buenos Aires",
+///   "location": { "latitude": -34.6037, "longitude": -58.3816, "altitude": 0.025 },
+///   "elevation_mask": {
+///     "minimum": 5.0,
+///     "horizon": [
+///       { "azimuth": 0.0, "elevation": 8.0 },
+///       { "azimuth": 180.0, "elevation": 3.0 }
+///     ]
+///   },
+///   "bands": [
+///     { "min_frequency": 144000000, "max_frequency": 146000000, "receive": true, "transmit": true },
+///     { "min_frequency": 435000000, "max_frequency": 438000000, "receive": true, "transmit": false }
+///   ],
+///   "rotator": {
+///     "mode": "Standard",
+///     "min_azimuth": 0.0,
+///     "max_azimuth": 360.0,
+///     "min_elevation": 0.0,
+///     "max_elevation": 90.0,
+///     "azimuth_rate": 6.0,
+///     "elevation_rate": 6.0
+///   },
+///   "transmitter": { "licensed": true, "callsign": "LU1ABC", "max_power": 50.0 },
+///   "timezone": "America/Argentina/Buenos_Aires"
+/// }
+/// ```
+///
+/// ## Example
+/// ```
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::stations::{FrequencyBand, GroundStation};
+///
+/// let id = GroundStationId::new("gs-buenos-aires").unwrap();
+/// let mut station = GroundStation::new(id, Geodetic::new(-34.6037, -58.3816, 0.025));
+/// station.bands.push(FrequencyBand::receive_only(144_000_000.0, 146_000_000.0));
+///
+/// assert!(station.validate().is_ok());
+/// assert!(station.can_receive(145_800_000.0));
+/// assert!(!station.can_transmit(145_800_000.0));
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct GroundStation {
+    /// Unique identifier, used in MQTT topics.
+    pub id: GroundStationId,
+    /// Human-readable name.
+    #[schema(example = "Buenos Aires")]
+    pub name: String,
+    /// Antenna location.
+    pub location: Geodetic,
+    /// Minimum elevation at which satellites can be tracked.
+    pub elevation_mask: ElevationMask,
+    /// Frequency ranges the station's radios cover.
+    pub bands: Vec<FrequencyBand>,
+    /// Mechanical limits of the antenna rotator.
+    pub rotator: RotatorLimits,
+    /// Transmitter, or `None` for receive-only stations.
+    pub transmitter: Option<Transmitter>,
+    /// IANA time zone of the station, for operator-facing times.
+    #[schema(example = "America/Argentina/Buenos_Aires")]
+    pub timezone: String,
+}
+
+/// # Elevation Mask
+///
+/// The effective mask at an azimuth is the greater of `minimum` and the
+/// horizon profile, which is linearly interpolated between its points
+/// (wrapping around north).
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct ElevationMask {
+    /// Mask in every direction, in degrees.
+    #[schema(example = 5.0)]
+    pub minimum: f64,
+    /// Obstructions by azimuth, sorted by increasing azimuth. May be empty.
+    #[serde(default)]
+    pub horizon: Vec<HorizonPoint>,
+}
+
+/// A point of a horizon profile.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct HorizonPoint {
+    /// Azimuth, in degrees within `[0, 360)`.
+    #[schema(example = 0.0)]
+    pub azimuth: f64,
+    /// Elevation of the horizon at `azimuth`, in degrees.
+    #[schema(example = 8.0)]
+    pub elevation: f64,
+}
+
+/// A frequency range covered by the station's radios.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyBand {
+    /// Lower bound, in Hertz.
+    #[schema(example = 144000000)]
+    pub min_frequency: f64,
+    /// Upper bound, in Hertz.
+    #[schema(example = 146000000)]
+    pub max_frequency: f64,
+    /// Whether the station can receive in this band.
+    pub receive: bool,
+    /// Whether the station's hardware can transmit in this band.
+    pub transmit: bool,
+}
+
+/// # Rotator Limits
+///
+/// Mechanical range and slew rates of the antenna rotator.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq)]
+pub struct RotatorLimits {
+    pub mode: RotatorMode,
+    /// Azimuth range, in degrees.
+    #[schema(example = 0.0)]
+    pub min_azimuth: f64,
+    #[schema(example = 360.0)]
+    pub max_azimuth: f64,
+    /// Elevation range, in degrees. Up to 180 for
+    /// [`RotatorMode::Flip`] rotators.
+    #[schema(example = 0.0)]
+    pub min_elevation: f64,
+    #[schema(example = 90.0)]
+    pub max_elevation: f64,
+    /// Maximum azimuth slew rate, in degrees per second.
+    #[schema(example = 6.0)]
+    pub azimuth_rate: f64,
+    /// Maximum elevation slew rate, in degrees per second.
+    #[schema(example = 6.0)]
+    pub elevation_rate: f64,
+}
+
+/// Transmission capability and license of a station.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct Transmitter {
+    /// Whether the station holds a license to transmit. Unlicensed
+    /// transmitters must not be used.
+    pub licensed: bool,
+    /// Callsign the station transmits under, if any.
+    #[schema(example = "LU1ABC")]
+    pub callsign: Option<String>,
+    /// Maximum transmitter output power, in watts.
+    #[schema(example = 50.0)]
+    pub max_power: Option<f64>,
+}
+
+impl GroundStation {
+    /// A receive-only station with no bands, a 0° mask, a standard rotator
+    /// and UTC time zone. Its name is its id.
+    pub fn new(id: GroundStationId, location: Geodetic) -> Self {
+        GroundStation {
+            name: id.to_string(),
+            id,
+            location,
+            elevation_mask: ElevationMask::default(),
+            bands: Vec::new(),
+            rotator: RotatorLimits::default(),
+            transmitter: None,
+            timezone: "UTC".to_string(),
+        }
+    }
+
+    /// Checks that the description is self-consistent.
+    pub fn validate(&self) -> Result<(), GroundStationError> {
+        if !(-90.0..=90.0).contains(&self.location.latitude)
+            || !(-180.0..=180.0).contains(&self.location.longitude)
+        {
+            return Err(GroundStationError::InvalidLocation);
+        }
+        self.elevation_mask.validate()?;
+        for (index, band) in self.bands.iter().enumerate() {
+            if !(band.min_frequency > 0.0 && band.min_frequency < band.max_frequency) {
+                return Err(GroundStationError::InvalidFrequencyBand { index });
+            }
+        }
+        self.rotator.validate()
+    }
+
+    /// Whether a satellite at `look` is above the elevation mask.
+    pub fn is_visible(&self, look: &Topocentric) -> bool {
+        look.elevation >= self.elevation_mask.at(look.azimuth)
+    }
+
+    /// Whether any band can receive `frequency` (Hz).
+    pub fn can_receive(&self, frequency: f64) -> bool {
+        self.bands
+            .iter()
+            .any(|band| band.receive && band.contains(frequency))
+    }
+
+    /// Whether the station has a licensed transmitter and a band that can
+    /// transmit `frequency` (Hz).
+    pub fn can_transmit(&self, frequency: f64) -> bool {
+        self.transmitter
+            .as_ref()
+            .is_some_and(|transmitter| transmitter.licensed)
+            && self.has_transmit_band(frequency)
+    }
+
+    /// Whether any band's hardware can transmit `frequency` (Hz), regardless
+    /// of the transmitter and its license.
+    pub fn has_transmit_band(&self, frequency: f64) -> bool {
+        self.bands
+            .iter()
+            .any(|band| band.transmit && band.contains(frequency))
+    }
+}
+
+impl ElevationMask {
+    /// A mask with the same elevation in every direction.
+    pub fn constant(elevation: f64) -> Self {
+        ElevationMask {
+            minimum: elevation,
+            horizon: Vec::new(),
+        }
+    }
+
+    /// Effective mask at `azimuth` (degrees).
+    ///
+    /// ## Example
+    /// ```
+    /// use rustar_types::stations::{ElevationMask, HorizonPoint};
+    ///
+    /// let mask = ElevationMask {
+    ///     minimum: 5.0,
+    ///     horizon: vec![
+    ///         HorizonPoint { azimuth: 90.0, elevation: 15.0 },
+    ///         HorizonPoint { azimuth: 270.0, elevation: 0.0 },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(mask.at(90.0), 15.0);
+    /// assert_eq!(mask.at(180.0), 7.5);
+    /// assert_eq!(mask.at(0.0), 7.5);
+    /// assert_eq!(mask.at(270.0), 5.0);
+    /// ```
+    pub fn at(&self, azimuth: f64) -> f64 {
+        self.minimum.max(self.horizon_at(azimuth.rem_euclid(360.0)))
+    }
+
+    fn horizon_at(&self, azimuth: f64) -> f64 {
+        let points = &self.horizon;
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return f64::NEG_INFINITY;
+        };
+
+        // The segment containing the azimuth, wrapping from the last point
+        // to the first one through north.
+        let next = points.partition_point(|point| point.azimuth <= azimuth);
+        let (from, to) = match next {
+            0 => (
+                HorizonPoint {
+                    azimuth: last.azimuth - 360.0,
+                    ..*last
+                },
+                *first,
+            ),
+            n if n == points.len() => (
+                *last,
+                HorizonPoint {
+                    azimuth: first.azimuth + 360.0,
+                    ..*first
+                },
+            ),
+            n => (points[n - 1], points[n]),
+        };
+
+        let span = to.azimuth - from.azimuth;
+        if span <= 0.0 {
+            return from.elevation;
+        }
+        from.elevation + (to.elevation - from.elevation) * (azimuth - from.azimuth) / span
+    }
+
+    fn validate(&self) -> Result<(), GroundStationError> {
+        let in_range = |elevation: f64| (-90.0..=90.0).contains(&elevation);
+        if !in_range(self.minimum) || !self.horizon.iter().all(|point| in_range(point.elevation)) {
+            return Err(GroundStationError::InvalidElevationMask);
+        }
+        let azimuths_valid = self
+            .horizon
+            .iter()
+            .all(|point| (0.0..360.0).contains(&point.azimuth))
+            && self
+                .horizon
+                .windows(2)
+                .all(|pair| pair[0].azimuth < pair[1].azimuth);
+        if !azimuths_valid {
+            return Err(GroundStationError::InvalidHorizonProfile);
+        }
+        Ok(())
+    }
+}
+
+impl Default for ElevationMask {
+    fn default() -> Self {
+        ElevationMask::constant(0.0)
+    }
+}
+
+impl FrequencyBand {
+    pub fn receive_only(min_frequency: f64, max_frequency: f64) -> Self {
+        FrequencyBand {
+            min_frequency,
+            max_frequency,
+            receive: true,
+            transmit: false,
+        }
+    }
+
+    pub fn transceive(min_frequency: f64, max_frequency: f64) -> Self {
+        FrequencyBand {
+            transmit: true,
+            ..FrequencyBand::receive_only(min_frequency, max_frequency)
+        }
+    }
+
+    /// Whether `frequency` (Hz) lies within the band.
+    pub fn contains(&self, frequency: f64) -> bool {
+        (self.min_frequency..=self.max_frequency).contains(&frequency)
+    }
+}
+
+impl RotatorLimits {
+    /// Whether the rotator can point at `azimuth`, `elevation` (degrees).
+    pub fn can_point(&self, azimuth: f64, elevation: f64) -> bool {
+        (self.min_azimuth..=self.max_azimuth).contains(&azimuth)
+            && (self.min_elevation..=self.max_elevation).contains(&elevation)
+    }
+
+    fn validate(&self) -> Result<(), GroundStationError> {
+        let valid = self.min_azimuth < self.max_azimuth
+            && self.min_elevation < self.max_elevation
+            && self.azimuth_rate > 0.0
+            && self.elevation_rate > 0.0;
+        if !valid {
+            return Err(GroundStationError::InvalidRotatorLimits);
+        }
+        Ok(())
+    }
+}
+
+impl Default for RotatorLimits {
+    /// A standard az/el rotator with full range and 6°/s slew rates.
+    fn default() -> Self {
+        RotatorLimits {
+            mode: RotatorMode::Standard,
+            min_azimuth: 0.0,
+            max_azimuth: 360.0,
+            min_elevation: 0.0,
+            max_elevation: 90.0,
+            azimuth_rate: 6.0,
+            elevation_rate: 6.0,
+        }
+    }
+}
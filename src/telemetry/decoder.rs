@@ -0,0 +1,333 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::TelemetryRecord;
+use crate::codec::crc16_ccitt;
+use crate::mqtt::telemetry::TelemetryMessage;
+
+/// Channel names that populate the fields of a [`TelemetryRecord`].
+pub const TEMPERATURE: &str = "temperature";
+pub const VOLTAGE: &str = "voltage";
+pub const CURRENT: &str = "current";
+pub const BATTERY_LEVEL: &str = "battery_level";
+
+/// Error type for telemetry frame decoding failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum TelemetryDecodeError {
+    /// No decoder is registered for the satellite
+    UnknownSatellite { satellite_id: String },
+    /// The frame is shorter than its layout
+    Truncated { expected: usize, found: usize },
+    /// The frame is longer than its layout
+    TrailingBytes { expected: usize, found: usize },
+    /// The frame checksum does not match its contents
+    ChecksumMismatch { expected: u16, found: u16 },
+    /// A channel decoded to a value that is not finite
+    InvalidValue { channel: String },
+    /// A channel required by [`TelemetryRecord`] is not in the frame
+    MissingChannel { channel: String },
+}
+
+impl fmt::Display for TelemetryDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TelemetryDecodeError::*;
+        match self {
+            UnknownSatellite { satellite_id } => {
+                write!(f, "no decoder for satellite {satellite_id}")
+            }
+            Truncated { expected, found } => write!(
+                f,
+                "frame is too short: expected {expected} bytes, found {found}"
+            ),
+            TrailingBytes { expected, found } => write!(
+                f,
+                "frame is too long: expected {expected} bytes, found {found}"
+            ),
+            ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected {expected:#06x}, found {found:#06x}"
+            ),
+            InvalidValue { channel } => write!(f, "channel {channel} is not finite"),
+            MissingChannel { channel } => write!(f, "missing channel {channel}"),
+        }
+    }
+}
+
+impl std::error::Error for TelemetryDecodeError {}
+
+/// # Decoded Telemetry
+///
+/// A telemetry frame converted to engineering units.
+#[derive(Debug)]
+pub struct DecodedTelemetry {
+    /// The standard housekeeping values of the frame.
+    pub record: TelemetryRecord,
+    /// Every decoded channel by name, including those in `record`.
+    pub channels: BTreeMap<String, f64>,
+}
+
+/// Converts the raw payload of a [`TelemetryMessage`] from one satellite
+/// into typed telemetry.
+pub trait TelemetryDecoder: Send + Sync {
+    fn decode(&self, message: &TelemetryMessage) -> Result<DecodedTelemetry, TelemetryDecodeError>;
+}
+
+/// # Decoder Registry
+///
+/// Telemetry decoders keyed by satellite id, so a backend can route every
+/// downlinked payload to the decoder for its satellite's frame format.
+///
+/// ## Example
+/// ```
+/// use chrono::Utc;
+/// use rustar_types::mqtt::telemetry::TelemetryMessage;
+/// use rustar_types::mqtt::topics::GroundStationId;
+/// use rustar_types::telemetry::{
+///     ByteOrder, DecoderRegistry, FieldKind, LinearDecoder, TelemetryDecodeError,
+/// };
+///
+/// let beacon = LinearDecoder::new(ByteOrder::Big)
+///     .field("temperature", FieldKind::I16, 0.01, 0.0)
+///     .field("voltage", FieldKind::U16, 0.001, 0.0)
+///     .field("current", FieldKind::I16, 0.001, 0.0)
+///     .field("battery_level", FieldKind::U8, 1.0, 0.0)
+///     .with_checksum();
+///
+/// let mut registry = DecoderRegistry::new();
+/// registry.register("25544", beacon);
+///
+/// let gs_1 = GroundStationId::new("gs-1").unwrap();
+/// let payload = vec![0x09, 0xC4, 0x1F, 0x40, 0xFF, 0x38, 0x57, 0x04, 0xE1];
+/// let message = TelemetryMessage::new(gs_1.clone(), Utc::now(), payload);
+///
+/// let decoded = registry.decode("25544", &message).unwrap();
+/// assert_eq!(decoded.record.temperature, 25.0);
+/// assert_eq!(decoded.record.voltage, 8.0);
+/// assert_eq!(decoded.record.current, -0.2);
+/// assert_eq!(decoded.record.battery_level, 87);
+///
+/// let truncated = TelemetryMessage::new(gs_1, Utc::now(), vec![0x09, 0xC4]);
+/// assert_eq!(
+///     registry.decode("25544", &truncated).unwrap_err(),
+///     TelemetryDecodeError::Truncated { expected: 9, found: 2 }
+/// );
+/// ```
+#[derive(Default)]
+pub struct DecoderRegistry {
+    decoders: HashMap<String, Box<dyn TelemetryDecoder>>,
+}
+
+impl DecoderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the decoder for a satellite, returning the one it replaces.
+    pub fn register(
+        &mut self,
+        satellite_id: impl Into<String>,
+        decoder: impl TelemetryDecoder + 'static,
+    ) -> Option<Box<dyn TelemetryDecoder>> {
+        self.decoders.insert(satellite_id.into(), Box::new(decoder))
+    }
+
+    /// Removes the decoder of a satellite.
+    pub fn unregister(&mut self, satellite_id: &str) -> Option<Box<dyn TelemetryDecoder>> {
+        self.decoders.remove(satellite_id)
+    }
+
+    pub fn get(&self, satellite_id: &str) -> Option<&dyn TelemetryDecoder> {
+        self.decoders
+            .get(satellite_id)
+            .map(|decoder| decoder.as_ref())
+    }
+
+    /// Decodes a message downlinked from `satellite_id`.
+    pub fn decode(
+        &self,
+        satellite_id: &str,
+        message: &TelemetryMessage,
+    ) -> Result<DecodedTelemetry, TelemetryDecodeError> {
+        self.get(satellite_id)
+            .ok_or_else(|| TelemetryDecodeError::UnknownSatellite {
+                satellite_id: satellite_id.to_string(),
+            })?
+            .decode(message)
+    }
+}
+
+/// Byte order of the fields of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    Big,
+    Little,
+}
+
+/// Raw encoding of a frame field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+}
+
+impl FieldKind {
+    /// Size of the field, in bytes.
+    pub fn size(self) -> usize {
+        match self {
+            FieldKind::U8 | FieldKind::I8 => 1,
+            FieldKind::U16 | FieldKind::I16 => 2,
+            FieldKind::U32 | FieldKind::I32 | FieldKind::F32 => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    kind: FieldKind,
+    scale: f64,
+    offset: f64,
+}
+
+/// # Linear Decoder
+///
+/// Decoder for fixed-layout frames of consecutive numeric fields, each
+/// converted to engineering units as `raw * scale + offset`.
+///
+/// The frame may end with a big-endian CRC-16/CCITT-FALSE over the fields.
+/// Channels named [`TEMPERATURE`], [`VOLTAGE`], [`CURRENT`] and
+/// [`BATTERY_LEVEL`] fill the decoded [`TelemetryRecord`], whose timestamp
+/// is the reception time of the message.
+#[derive(Debug, Clone)]
+pub struct LinearDecoder {
+    byte_order: ByteOrder,
+    fields: Vec<Field>,
+    checksum: bool,
+}
+
+impl LinearDecoder {
+    pub fn new(byte_order: ByteOrder) -> Self {
+        LinearDecoder {
+            byte_order,
+            fields: Vec::new(),
+            checksum: false,
+        }
+    }
+
+    /// Appends a field after the previous ones.
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        kind: FieldKind,
+        scale: f64,
+        offset: f64,
+    ) -> Self {
+        self.fields.push(Field {
+            name: name.into(),
+            kind,
+            scale,
+            offset,
+        });
+        self
+    }
+
+    /// Expects a CRC-16 trailer after the fields.
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = true;
+        self
+    }
+
+    /// Total frame length, in bytes.
+    pub fn frame_length(&self) -> usize {
+        let fields: usize = self.fields.iter().map(|field| field.kind.size()).sum();
+        fields + if self.checksum { 2 } else { 0 }
+    }
+
+    fn read(&self, kind: FieldKind, bytes: &[u8]) -> f64 {
+        macro_rules! read {
+            ($t:ty) => {{
+                let bytes = bytes.try_into().unwrap();
+                match self.byte_order {
+                    ByteOrder::Big => <$t>::from_be_bytes(bytes),
+                    ByteOrder::Little => <$t>::from_le_bytes(bytes),
+                }
+            }};
+        }
+
+        match kind {
+            FieldKind::U8 => bytes[0] as f64,
+            FieldKind::I8 => bytes[0] as i8 as f64,
+            FieldKind::U16 => read!(u16) as f64,
+            FieldKind::I16 => read!(i16) as f64,
+            FieldKind::U32 => read!(u32) as f64,
+            FieldKind::I32 => read!(i32) as f64,
+            FieldKind::F32 => read!(f32) as f64,
+        }
+    }
+}
+
+impl TelemetryDecoder for LinearDecoder {
+    fn decode(&self, message: &TelemetryMessage) -> Result<DecodedTelemetry, TelemetryDecodeError> {
+        let frame = &message.payload;
+        let expected = self.frame_length();
+        if frame.len() < expected {
+            return Err(TelemetryDecodeError::Truncated {
+                expected,
+                found: frame.len(),
+            });
+        }
+        if frame.len() > expected {
+            return Err(TelemetryDecodeError::TrailingBytes {
+                expected,
+                found: frame.len(),
+            });
+        }
+
+        if self.checksum {
+            let (data, trailer) = frame.split_at(expected - 2);
+            let found = u16::from_be_bytes([trailer[0], trailer[1]]);
+            let expected = crc16_ccitt(data);
+            if found != expected {
+                return Err(TelemetryDecodeError::ChecksumMismatch { expected, found });
+            }
+        }
+
+        let mut channels = BTreeMap::new();
+        let mut position = 0;
+        for field in &self.fields {
+            let size = field.kind.size();
+            let value = self.read(field.kind, &frame[position..position + size]) * field.scale
+                + field.offset;
+            if !value.is_finite() {
+                return Err(TelemetryDecodeError::InvalidValue {
+                    channel: field.name.clone(),
+                });
+            }
+            channels.insert(field.name.clone(), value);
+            position += size;
+        }
+
+        let channel = |name: &str| {
+            channels
+                .get(name)
+                .copied()
+                .ok_or_else(|| TelemetryDecodeError::MissingChannel {
+                    channel: name.to_string(),
+                })
+        };
+        let record = TelemetryRecord::new(
+            message.timestamp.timestamp(),
+            channel(TEMPERATURE)? as f32,
+            channel(VOLTAGE)? as f32,
+            channel(CURRENT)? as f32,
+            channel(BATTERY_LEVEL)?.round() as i32,
+        );
+
+        Ok(DecodedTelemetry { record, channels })
+    }
+}
@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+mod decoder;
+
+pub use decoder::{
+    BATTERY_LEVEL, ByteOrder, CURRENT, DecodedTelemetry, DecoderRegistry, FieldKind, LinearDecoder,
+    TEMPERATURE, TelemetryDecodeError, TelemetryDecoder, VOLTAGE,
+};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TelemetryRecord {
     pub id: String,
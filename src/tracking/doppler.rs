@@ -0,0 +1,104 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{TrackingError, sample_times};
+use crate::frames::Geodetic;
+use crate::jobs::Job;
+
+/// Speed of light in vacuum, in km/s.
+pub const SPEED_OF_LIGHT: f64 = 299_792.458;
+
+/// # Doppler Sample
+///
+/// Radio frequencies to use at a given instant of a pass.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "time": "2025-09-19T12:00:00Z",
+///   "range_rate": -6.512,
+///   "downlink_frequency": 145803167.4,
+///   "uplink_frequency": 437490504.1
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct DopplerSample {
+    /// UTC timestamp of the sample.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub time: DateTime<Utc>,
+    /// Range rate between station and satellite, in km/s (positive when
+    /// receding).
+    #[schema(example = -6.512)]
+    pub range_rate: f64,
+    /// Frequency to **receive** on, in Hertz: the job's `rx_frequency` as
+    /// shifted by the satellite's motion.
+    #[schema(example = 145803167.4)]
+    pub downlink_frequency: f64,
+    /// Frequency to **transmit** on, in Hertz, so the satellite receives the
+    /// job's nominal `tx_frequency`.
+    #[schema(example = 437490504.1)]
+    pub uplink_frequency: f64,
+}
+
+/// Computes Doppler-corrected downlink and uplink frequencies for the whole
+/// `start`..`end` window of `job`, every `step`, as seen from `observer`.
+///
+/// The first sample is at `start` and the last one at `end`, even when the
+/// window is not a multiple of `step`.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::Job;
+/// use rustar_types::tracking::doppler_schedule;
+///
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 36, 18).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 47, 14).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+/// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+///
+/// let schedule = doppler_schedule(&job, &buenos_aires, Duration::seconds(1)).unwrap();
+///
+/// // Approaching at AOS, receding at LOS
+/// let (first, last) = (&schedule[0], schedule.last().unwrap());
+/// assert!(first.downlink_frequency > job.rx_frequency);
+/// assert!(last.downlink_frequency < job.rx_frequency);
+/// assert_eq!(last.time, job.end);
+/// ```
+pub fn doppler_schedule(
+    job: &Job,
+    observer: &Geodetic,
+    step: Duration,
+) -> Result<Vec<DopplerSample>, TrackingError> {
+    let propagator = job.orbit.propagator()?;
+
+    sample_times(job.start, job.end, step)?
+        .into_iter()
+        .map(|time| {
+            let range_rate = propagator.observe(observer, time)?.range_rate;
+            Ok(DopplerSample {
+                time,
+                range_rate,
+                downlink_frequency: job.rx_frequency * downlink_factor(range_rate),
+                uplink_frequency: job.tx_frequency / downlink_factor(range_rate),
+            })
+        })
+        .collect()
+}
+
+/// Ratio between received and transmitted frequency for a signal travelling
+/// from a source moving at `range_rate` (km/s) relative to the receiver.
+fn downlink_factor(range_rate: f64) -> f64 {
+    1.0 - range_rate / SPEED_OF_LIGHT
+}
@@ -0,0 +1,72 @@
+//! # Tracking
+//!
+//! Time series derived from a [`Job`](crate::jobs::Job) window that ground
+//! station hardware consumes during a pass: radio retuning and antenna
+//! pointing, and the Sun and Moon transits that pointing runs into.
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::sgp4::Sgp4Error;
+
+mod doppler;
+mod pointing;
+mod sun_transit;
+
+pub use doppler::{DopplerSample, SPEED_OF_LIGHT, doppler_schedule};
+pub use pointing::{PointingSample, RotatorMode, pointing_track};
+pub use sun_transit::{CelestialBody, TransitInterval, celestial_transits};
+
+/// Error type for tracking schedule generation failures
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackingError {
+    /// The sampling step is not a positive duration
+    InvalidStep,
+    /// The job window is empty (`start` is not before `end`)
+    InvalidWindow,
+    /// The satellite could not be propagated
+    Propagation(Sgp4Error),
+}
+
+impl fmt::Display for TrackingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use TrackingError::*;
+        match self {
+            InvalidStep => f.write_str("sampling step is not positive"),
+            InvalidWindow => f.write_str("start is not before end"),
+            Propagation(error) => write!(f, "propagation failed: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for TrackingError {}
+
+impl From<Sgp4Error> for TrackingError {
+    fn from(error: Sgp4Error) -> Self {
+        TrackingError::Propagation(error)
+    }
+}
+
+/// Evenly spaced times from `start` to `end`, always including both ends.
+pub(crate) fn sample_times(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    step: Duration,
+) -> Result<Vec<DateTime<Utc>>, TrackingError> {
+    if step <= Duration::zero() {
+        return Err(TrackingError::InvalidStep);
+    }
+    if start >= end {
+        return Err(TrackingError::InvalidWindow);
+    }
+
+    let mut times = Vec::new();
+    let mut time = start;
+    while time < end {
+        times.push(time);
+        time += step;
+    }
+    times.push(end);
+    Ok(times)
+}
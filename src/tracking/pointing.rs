@@ -0,0 +1,185 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{TrackingError, sample_times};
+use crate::frames::Geodetic;
+use crate::jobs::Job;
+
+/// # Rotator Mode
+///
+/// Mechanical range of the antenna rotator a track is generated for.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotatorMode {
+    /// Azimuth `0..=360`, elevation `0..=90`.
+    ///
+    /// Passes that cross north require the rotator to unwind, and passes
+    /// near the zenith require a fast azimuth swing.
+    Standard,
+    /// Azimuth `0..=360`, elevation `0..=180`.
+    ///
+    /// Every direction can also be reached "over the top" as
+    /// `(azimuth + 180, 180 - elevation)`, which the track uses to avoid
+    /// crossing north and to follow passes through the zenith without an
+    /// azimuth swing.
+    Flip,
+}
+
+/// # Pointing Sample
+///
+/// Antenna position for a given instant of a pass.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "time": "2025-09-19T12:00:00Z",
+///   "azimuth": 212.43,
+///   "elevation": 10.61,
+///   "range": 1571.2,
+///   "flipped": false
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct PointingSample {
+    /// UTC timestamp of the sample.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:00:00Z")]
+    pub time: DateTime<Utc>,
+    /// Rotator azimuth, in degrees within `[0, 360)`.
+    #[schema(example = 212.43)]
+    pub azimuth: f64,
+    /// Rotator elevation, in degrees, corrected for atmospheric refraction.
+    /// Above 90 only for flipped samples.
+    #[schema(example = 10.61)]
+    pub elevation: f64,
+    /// Slant range to the satellite, in kilometers.
+    #[schema(example = 1571.2)]
+    pub range: f64,
+    /// Whether the sample uses the "over the top" position of a
+    /// [`RotatorMode::Flip`] rotator.
+    #[schema(example = false)]
+    pub flipped: bool,
+}
+
+/// Computes the antenna pointing track for the whole `start`..`end` window
+/// of `job`, every `step`, as seen from `observer`.
+///
+/// Elevations include atmospheric refraction for standard conditions, so
+/// the antenna points where the signal actually comes from.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::Job;
+/// use rustar_types::tracking::{RotatorMode, pointing_track};
+///
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 36, 18).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 47, 14).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+/// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+///
+/// let track = pointing_track(&job, &buenos_aires, RotatorMode::Flip, Duration::seconds(5)).unwrap();
+///
+/// // The azimuth never jumps across north
+/// for pair in track.windows(2) {
+///     assert!((pair[1].azimuth - pair[0].azimuth).abs() < 180.0);
+/// }
+/// ```
+pub fn pointing_track(
+    job: &Job,
+    observer: &Geodetic,
+    mode: RotatorMode,
+    step: Duration,
+) -> Result<Vec<PointingSample>, TrackingError> {
+    let propagator = job.orbit.propagator()?;
+
+    let samples = sample_times(job.start, job.end, step)?
+        .into_iter()
+        .map(|time| {
+            let look = propagator.observe(observer, time)?;
+            Ok(PointingSample {
+                time,
+                azimuth: look.azimuth,
+                elevation: refracted_elevation(look.elevation),
+                range: look.range,
+                flipped: false,
+            })
+        })
+        .collect::<Result<Vec<_>, TrackingError>>()?;
+
+    Ok(match mode {
+        RotatorMode::Standard => samples,
+        RotatorMode::Flip => {
+            // Try both starting positions and keep the one needing less travel
+            let normal = follow(samples.clone(), false);
+            let flipped = follow(samples, true);
+            if travel(&flipped) < travel(&normal) {
+                flipped
+            } else {
+                normal
+            }
+        }
+    })
+}
+
+/// Chooses, for each sample, whichever of the two equivalent rotator
+/// positions is closest to the previous one.
+fn follow(mut samples: Vec<PointingSample>, start_flipped: bool) -> Vec<PointingSample> {
+    let mut previous: Option<(f64, f64)> = None;
+
+    for sample in &mut samples {
+        let normal = (sample.azimuth, sample.elevation);
+        let over = ((sample.azimuth + 180.0) % 360.0, 180.0 - sample.elevation);
+
+        sample.flipped = match previous {
+            None => start_flipped,
+            Some(previous) => move_cost(previous, over) < move_cost(previous, normal),
+        };
+        if sample.flipped {
+            (sample.azimuth, sample.elevation) = over;
+        }
+        previous = Some((sample.azimuth, sample.elevation));
+    }
+
+    samples
+}
+
+/// Total rotator travel along a track.
+fn travel(samples: &[PointingSample]) -> f64 {
+    samples
+        .windows(2)
+        .map(|pair| {
+            move_cost(
+                (pair[0].azimuth, pair[0].elevation),
+                (pair[1].azimuth, pair[1].elevation),
+            )
+        })
+        .sum()
+}
+
+/// Time-equivalent cost of moving between two rotator positions, assuming
+/// both axes move at the same rate simultaneously. Azimuth cannot wrap
+/// through north, so the distance is linear.
+fn move_cost(from: (f64, f64), to: (f64, f64)) -> f64 {
+    (to.0 - from.0).abs().max((to.1 - from.1).abs())
+}
+
+/// Apparent elevation of an object at geometric elevation `elevation`
+/// (degrees), using Sæmundsson's refraction formula for 1010 hPa and 10 °C.
+pub(super) fn refracted_elevation(elevation: f64) -> f64 {
+    // The formula diverges well below the horizon, where refraction is moot
+    if elevation < -1.0 {
+        return elevation;
+    }
+    let refraction_arcmin = 1.02 / (elevation + 10.3 / (elevation + 5.11)).to_radians().tan();
+    elevation + refraction_arcmin / 60.0
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::PointingSample;
+use super::pointing::refracted_elevation;
+use crate::ephemeris::{moon_position, sun_position};
+use crate::frames::{Geodetic, teme_to_ecef};
+use crate::sgp4::StateVector;
+
+/// # Celestial Body
+///
+/// A radio-loud body the antenna should not point at.
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CelestialBody {
+    Sun,
+    Moon,
+}
+
+impl CelestialBody {
+    /// Azimuth and apparent elevation of the body as seen from `observer`
+    /// at `time`, in degrees.
+    pub fn look_angles(self, observer: &Geodetic, time: DateTime<Utc>) -> (f64, f64) {
+        let position = match self {
+            CelestialBody::Sun => sun_position(time),
+            CelestialBody::Moon => moon_position(time),
+        };
+        let state = StateVector {
+            position,
+            velocity: [0.0; 3],
+        };
+        let look = observer.look_at(&teme_to_ecef(&state, time));
+        (look.azimuth, refracted_elevation(look.elevation))
+    }
+}
+
+/// # Transit Interval
+///
+/// A time span during which a celestial body is close to the antenna
+/// boresight.
+///
+/// Example JSON:
+/// ```json
+/// {
+///   "body": "Sun",
+///   "start": "2025-09-19T12:04:10Z",
+///   "end": "2025-09-19T12:05:40Z",
+///   "min_separation": 1.82
+/// }
+/// ```
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone, PartialEq)]
+pub struct TransitInterval {
+    pub body: CelestialBody,
+    /// First sample of the track within the separation.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:04:10Z")]
+    pub start: DateTime<Utc>,
+    /// Last sample of the track within the separation.
+    #[schema(value_type = String, format = "date-time", example = "2025-09-19T12:05:40Z")]
+    pub end: DateTime<Utc>,
+    /// Smallest angle between the boresight and the body, in degrees.
+    #[schema(example = 1.82)]
+    pub min_separation: f64,
+}
+
+/// Finds the intervals of `track`, as pointed from `observer`, during which
+/// `body` is above the horizon and within `max_separation` degrees of the
+/// antenna boresight.
+///
+/// Intervals are as precise as the track's sampling step.
+///
+/// ## Example
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use rustar_types::frames::Geodetic;
+/// use rustar_types::jobs::Job;
+/// use rustar_types::tracking::{CelestialBody, RotatorMode, celestial_transits, pointing_track};
+///
+/// let job = Job::builder(12345)
+///     .tle_text("ISS (ZARYA)
+/// 1 25544U 98067A   25235.75642456  .00011222  00000+0  20339-3 0  9993
+/// 2 25544  51.6355 332.1708 0003307 260.2831  99.7785 15.50129787525648")
+///     .window(
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 36, 18).unwrap(),
+///         Utc.with_ymd_and_hms(2025, 8, 24, 1, 47, 14).unwrap(),
+///     )
+///     .rx_frequency(145_800_000.0)
+///     .tx_frequency(437_500_000.0)
+///     .build()
+///     .unwrap();
+/// let buenos_aires = Geodetic::new(-34.6037, -58.3816, 0.025);
+/// let track = pointing_track(&job, &buenos_aires, RotatorMode::Flip, Duration::seconds(5)).unwrap();
+///
+/// // A night pass never comes near the Sun
+/// assert!(celestial_transits(&track, &buenos_aires, CelestialBody::Sun, 10.0).is_empty());
+/// ```
+pub fn celestial_transits(
+    track: &[PointingSample],
+    observer: &Geodetic,
+    body: CelestialBody,
+    max_separation: f64,
+) -> Vec<TransitInterval> {
+    let mut intervals = Vec::new();
+    let mut current: Option<TransitInterval> = None;
+
+    for sample in track {
+        let (azimuth, elevation) = body.look_angles(observer, sample.time);
+        // A flipped sample points in the same direction as its normal
+        // position, so the separation needs no special case
+        let separation =
+            angular_separation((sample.azimuth, sample.elevation), (azimuth, elevation));
+
+        if elevation > 0.0 && separation <= max_separation {
+            match &mut current {
+                Some(interval) => {
+                    interval.end = sample.time;
+                    interval.min_separation = interval.min_separation.min(separation);
+                }
+                None => {
+                    current = Some(TransitInterval {
+                        body,
+                        start: sample.time,
+                        end: sample.time,
+                        min_separation: separation,
+                    })
+                }
+            }
+        } else if let Some(interval) = current.take() {
+            intervals.push(interval);
+        }
+    }
+
+    intervals.extend(current);
+    intervals
+}
+
+/// Angle between two directions given as `(azimuth, elevation)` in degrees.
+fn angular_separation(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (azimuth_a, elevation_a) = (a.0.to_radians(), a.1.to_radians());
+    let (azimuth_b, elevation_b) = (b.0.to_radians(), b.1.to_radians());
+    let cosine = elevation_a.sin() * elevation_b.sin()
+        + elevation_a.cos() * elevation_b.cos() * (azimuth_a - azimuth_b).cos();
+    cosine.clamp(-1.0, 1.0).acos().to_degrees()
+}